@@ -0,0 +1,126 @@
+//! Generates the embedded word lists from the `.txt` files in `src/dict`.
+//!
+//! Every `src/dict/<lang>.txt` becomes a `pub const <LANG>: &str` holding
+//! one word per line, deduplicated and sorted. The constant is named after
+//! the file with dashes turned into underscores, so `en-gb.txt` becomes
+//! `EN_GB`. Blank lines and lines starting with `#` are ignored; anything
+//! else must be exactly `LENGTH` lowercase letters of the language's
+//! alphabet or the build fails, listing every offending line. Lists hold
+//! words with their accents already folded.
+//!
+//! The letters accepted besides `a` to `z` are written out too, for tests
+//! only, so a test can check them against the alphabets in `src/lang.rs`.
+
+use std::{
+    env,
+    fmt::Write as _,
+    fs,
+    path::{Path, PathBuf},
+    process,
+};
+
+const DICT_DIR: &str = "src/dict";
+const LENGTH: usize = 5;
+
+/// Letters some languages use besides `a` to `z`, matching the alphabets in
+/// `src/lang.rs`; `lang::test::test_alphabets` fails if they drift apart.
+const EXTRA_LETTERS: [(&str, &str); 2] = [("de", "äöüß"), ("es", "ñ")];
+
+/// Validates a word list, returning its sorted words or one message per bad
+/// line.
+fn parse(path: &Path, extra: &str, contents: &str) -> Result<Vec<String>, Vec<String>> {
+    let mut words = Vec::new();
+    let mut errors = Vec::new();
+    for (n, line) in contents.lines().enumerate() {
+        let word = line.trim_end_matches('\r');
+        if word.trim().is_empty() || word.starts_with('#') {
+            continue;
+        }
+        let letter = |c: char| c.is_ascii_lowercase() || extra.contains(c);
+        if word.chars().count() == LENGTH && word.chars().all(letter) {
+            words.push(String::from(word));
+        } else {
+            errors.push(format!(
+                "{}:{}: {:?} is not {} lowercase letters (a-z{})",
+                path.display(),
+                n + 1,
+                word,
+                LENGTH,
+                extra
+            ));
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    words.sort_unstable();
+    words.dedup();
+    Ok(words)
+}
+
+/// Turns a file stem such as `en-gb` into a constant name such as `EN_GB`.
+fn const_name(stem: &str) -> String {
+    stem.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", DICT_DIR);
+
+    let mut paths: Vec<PathBuf> = fs::read_dir(DICT_DIR)
+        .unwrap_or_else(|e| panic!("can't read {}: {}", DICT_DIR, e))
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .collect();
+    paths.sort();
+
+    let mut out = String::new();
+    let mut failed = false;
+    for path in &paths {
+        println!("cargo:rerun-if-changed={}", path.display());
+        let contents = fs::read_to_string(path)
+            .unwrap_or_else(|e| panic!("can't read {}: {}", path.display(), e));
+        let stem = path.file_stem().unwrap().to_string_lossy();
+        let extra = EXTRA_LETTERS
+            .iter()
+            .find(|(lang, _)| *lang == stem)
+            .map_or("", |(_, letters)| letters);
+
+        match parse(path, extra, &contents) {
+            Ok(words) => {
+                writeln!(out, "/// Generated from `{}`.", path.display()).unwrap();
+                let name = const_name(&stem);
+                writeln!(out, "pub const {}: &str = {:?};", name, words.join("\n")).unwrap();
+            }
+            Err(errors) => {
+                failed = true;
+                for error in errors {
+                    eprintln!("error: {}", error);
+                }
+            }
+        }
+    }
+    if failed {
+        process::exit(1);
+    }
+    writeln!(out, "/// Letters accepted besides `a` to `z`, by language.").unwrap();
+    writeln!(out, "#[cfg(test)]").unwrap();
+    let extra = format!("{:?}", EXTRA_LETTERS);
+    writeln!(
+        out,
+        "pub const EXTRA_LETTERS: &[(&str, &str)] = &{};",
+        extra
+    )
+    .unwrap();
+
+    let dest = Path::new(&env::var("OUT_DIR").unwrap()).join("dict.rs");
+    fs::write(&dest, out).unwrap_or_else(|e| panic!("can't write {}: {}", dest.display(), e));
+}
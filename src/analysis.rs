@@ -0,0 +1,215 @@
+//! Post-mortem of a finished game: how far each guess narrowed down the
+//! answer, next to the guess the solver would have made in its place.
+//!
+//! Information is counted in bits: feedback that halves the words still
+//! fitting gives one bit. A guess is lucky when its feedback gave at least a
+//! bit more than it was expected to, on average over the words it could
+//! have been.
+
+use std::mem;
+
+use crate::{
+    difficulty,
+    word::{Word, WordList},
+    Game, Guess,
+};
+
+/// What one guess of a game achieved.
+pub(crate) struct Report {
+    pub(crate) word: String,
+
+    /// Words that fit the feedback before and after the guess.
+    pub(crate) before: usize,
+    pub(crate) after: usize,
+
+    /// Bits of information the feedback gave, and how many it was expected
+    /// to give.
+    pub(crate) bits: f64,
+    pub(crate) expected: f64,
+
+    /// The solver's best guess in the same spot, the word still fitting
+    /// that expects the most bits, and those bits, to compare with
+    /// `expected`.
+    pub(crate) solver: Option<Word>,
+    pub(crate) solver_expected: f64,
+
+    pub(crate) lucky: bool,
+
+    /// Whether the guess used every hint revealed before it.
+    pub(crate) hard_mode: bool,
+}
+
+/// The words with `length` letters that fit the feedback of every guess of
+/// `history`, in the order they are given.
+pub(crate) fn candidates<I: IntoIterator<Item = Word>>(
+    words: I,
+    length: usize,
+    history: &[(String, Guess)],
+) -> Vec<Word> {
+    let guesses: Vec<(Word, String)> = history
+        .iter()
+        .filter_map(|(word, guess)| Some((Word::parse(word)?, guess.pattern())))
+        .collect();
+    words
+        .into_iter()
+        .filter(|w| w.letters().count() == length)
+        .filter(|w| guesses.iter().all(|(g, p)| g.score(w).pattern() == *p))
+        .collect()
+}
+
+/// Reports on every guess of `game`, with the candidates taken from `dict`.
+pub(crate) fn analyze(dict: &WordList, game: &Game) -> Vec<Report> {
+    let length = game.word.chars().count();
+    let mut left = candidates(difficulty::ranked(dict), length, &[]);
+    let mut counts = vec![0; 3usize.pow(length as u32)];
+
+    let mut reports = vec![];
+    for (i, (word, _)) in game.history.iter().enumerate() {
+        let after = candidates(left.iter().copied(), length, &game.history[i..=i]);
+        let bits = bits(left.len(), after.len());
+        let expected = Word::parse(word).map_or(0.0, |guess| expected(&guess, &left, &mut counts));
+
+        let (solver, solver_expected) = match best(&left, &mut counts) {
+            Some((solver, solver_expected)) => (Some(solver), solver_expected),
+            None => (None, 0.0),
+        };
+
+        reports.push(Report {
+            word: word.clone(),
+            before: left.len(),
+            after: after.len(),
+            bits,
+            expected,
+            solver,
+            solver_expected,
+            lucky: bits >= expected + 1.0,
+            hard_mode: Game::hard_mode_violation(&game.history[..i], word).is_none(),
+        });
+        left = after;
+    }
+    reports
+}
+
+/// The word still fitting whose feedback is expected to give the most bits,
+/// the first in the solver's order on a tie, with the bits it expects.
+fn best(left: &[Word], counts: &mut [usize]) -> Option<(Word, f64)> {
+    let mut best: Option<(Word, f64)> = None;
+    for guess in left {
+        let bits = expected(guess, left, counts);
+        if best.is_none_or(|(_, most)| bits > most) {
+            best = Some((*guess, bits));
+        }
+    }
+    best
+}
+
+/// Information given by feedback narrowing `before` words down to `after`.
+fn bits(before: usize, after: usize) -> f64 {
+    if before == 0 || after == 0 {
+        return 0.0;
+    }
+    (before as f64 / after as f64).log2()
+}
+
+/// Average information the feedback of `guess` gives over `candidates`.
+///
+/// Candidates are grouped by feedback in `counts`, which has a zeroed slot for
+/// every feedback code and is left zeroed. The groups are summed in the order
+/// of the candidates so the same guess always expects exactly the same.
+fn expected(guess: &Word, candidates: &[Word], counts: &mut [usize]) -> f64 {
+    let codes: Vec<usize> = candidates
+        .iter()
+        .map(|c| guess.score_code(c) as usize)
+        .collect();
+    for &code in &codes {
+        counts[code] += 1;
+    }
+    let total = candidates.len() as f64;
+    let mut bits = 0.0;
+    for code in codes {
+        let n = mem::take(&mut counts[code]);
+        if n > 0 {
+            bits += n as f64 / total * (total / n as f64).log2();
+        }
+    }
+    bits
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const DICT: &str = "fight\nlight\nmight\nnight\nright\nsight\ntight\nfudge\nreads\nbread";
+
+    fn played(word: &str, guesses: &[&str]) -> Vec<Report> {
+        let dict = WordList::from(DICT);
+        let mut game = Game::with_word(&dict, word).unwrap();
+        for guess in guesses {
+            game.guess(String::from(*guess)).unwrap();
+        }
+        analyze(&dict, &game)
+    }
+
+    #[test]
+    fn test_analyze() {
+        let reports = played("tight", &["reads", "light", "tight"]);
+        assert_eq!(reports.len(), 3);
+        assert_eq!(
+            reports
+                .iter()
+                .map(|r| (r.before, r.after))
+                .collect::<Vec<_>>(),
+            [(10, 5), (5, 4), (4, 1)]
+        );
+        assert_eq!(reports[0].bits, 1.0);
+        assert!(reports.iter().all(|r| r.solver.is_some()));
+
+        // Picking the right one of four lookalikes is luck.
+        assert!(reports[2].lucky);
+        assert!(!reports[1].lucky);
+        assert!(reports.iter().all(|r| r.hard_mode));
+    }
+
+    #[test]
+    fn test_solver_expected() {
+        // The solver's guess is the best one among the words still fitting,
+        // so no guess of those expects more.
+        let games: [(&str, &[&str]); 3] = [
+            ("tight", &["reads", "light", "tight"]),
+            ("bread", &["fudge", "right", "bread"]),
+            ("night", &["fight", "light", "might", "night"]),
+        ];
+        for (word, guesses) in games {
+            for r in played(word, guesses) {
+                assert!(r.solver_expected >= r.expected, "{} {}", word, r.word);
+            }
+        }
+        let reports = played("tight", &["reads", "tight"]);
+        assert!(reports[0].solver_expected > 0.0);
+    }
+
+    #[test]
+    fn test_hard_mode() {
+        // FUDGE shows there's a D and an E, which RIGHT leaves out.
+        let reports = played("bread", &["fudge", "right", "bread"]);
+        assert!(reports[0].hard_mode);
+        assert!(!reports[1].hard_mode);
+    }
+
+    #[test]
+    fn test_expected() {
+        let dict = WordList::from(DICT);
+        let fudge = Word::parse("fudge").unwrap();
+        let reads = Word::parse("reads").unwrap();
+        let counts = &mut [0; 243];
+        assert_eq!(expected(&fudge, &[fudge, reads], counts), 1.0);
+        assert_eq!(expected(&fudge, &[fudge], counts), 0.0);
+        assert!(counts.iter().all(|&n| n == 0));
+
+        // Never more than telling every word apart would give.
+        let words = candidates(dict.iter(), 5, &[]);
+        assert!(expected(&reads, &words, counts) <= (words.len() as f64).log2());
+        assert_eq!(bits(4, 1), 2.0);
+        assert_eq!(bits(4, 0), 0.0);
+    }
+}
@@ -0,0 +1,579 @@
+//! Chat bot playing one game per user and channel.
+//!
+//! Messages starting with `!wordle` are turned into REPL commands: `!wordle
+//! reads` guesses a word, `!wordle /letters` lists unused letters and
+//! `!wordle /exit` gives up. A game starts on the first message and its share
+//! grid is posted to the channel as soon as it ends. `!wordle /daily` plays
+//! today's puzzle, whose results go to the leaderboard shown by `!wordle
+//! /leaderboard [daily|weekly|all]`. The chat service itself is abstracted
+//! behind the `Transport` trait; IRC is provided.
+//!
+//! Games are forgotten after an hour without a message from their player,
+//! and an abandoned daily puzzle counts as lost.
+
+use std::{
+    collections::HashMap,
+    io::{self, BufRead, BufReader, Write},
+    net::{TcpStream, ToSocketAddrs},
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    event::{Event, Format, Sink, Writer},
+    leaderboard::{self, Leaderboard, Period, Record},
+    palette::Palette,
+    today_puzzle,
+    word::WordList,
+    Command, Error, ErrorKind, Game, Mode,
+};
+
+/// Messages addressed to the bot start with this prefix.
+const PREFIX: &str = "!wordle";
+
+/// How long a game is kept around after its player's last message.
+const GAME_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// Nicknames tried by appending underscores when the one asked for is taken,
+/// before giving up.
+const NICK_ATTEMPTS: usize = 3;
+
+const HELP: &str = "Guess the five letter word with !wordle <guess>. \
+    Also: !wordle /letters to list unused letters, !wordle /hint for a hint, \
+    !wordle /exit to give up, !wordle /daily to play today's puzzle and \
+    !wordle /leaderboard [daily|weekly|all].";
+
+/// A chat message, either received by or sent from the bot.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub(crate) struct Message {
+    /// Where the message was posted, and where replies go.
+    pub(crate) channel: String,
+    pub(crate) user: String,
+    pub(crate) text: String,
+}
+
+/// A chat service the bot can talk through.
+pub(crate) trait Transport {
+    /// Waits for the next message, or `None` once the connection is closed.
+    fn recv(&mut self) -> Result<Option<Message>, Error>;
+
+    fn send(&mut self, channel: &str, text: &str) -> Result<(), Error>;
+}
+
+/// Keeps track of the games being played over a transport.
+pub(crate) struct Bot<'a> {
+    dict: &'a WordList,
+
+    /// Games in progress, keyed by channel and user, with the time of their
+    /// last message.
+    games: HashMap<(String, String), (Game, Instant)>,
+
+    /// Results of the daily puzzles played through the bot.
+    leaderboard: Leaderboard,
+}
+
+impl<'a> Bot<'a> {
+    pub(crate) fn new(dict: &'a WordList, leaderboard: Leaderboard) -> Self {
+        Self {
+            dict,
+            games: HashMap::new(),
+            leaderboard,
+        }
+    }
+
+    /// Relays messages between the transport and the games until the
+    /// transport closes.
+    pub(crate) fn run<T: Transport>(&mut self, transport: &mut T) -> Result<(), Error> {
+        while let Some(message) = transport.recv()? {
+            if let Err(e) = self.expire(Instant::now()) {
+                eprintln!("{}", e);
+            }
+            for line in self.handle(&message) {
+                transport.send(&message.channel, &line)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Forgets games whose player hasn't said anything within the ttl. Daily
+    /// puzzles left unfinished are recorded as lost.
+    pub(crate) fn expire(&mut self, now: Instant) -> Result<(), Error> {
+        let expired: Vec<(String, String)> = self
+            .games
+            .iter()
+            .filter(|(_, (_, last_message))| now.duration_since(*last_message) >= GAME_TTL)
+            .map(|(key, _)| key.clone())
+            .collect();
+
+        let mut result = Ok(());
+        for key in expired {
+            if let Some((game, _)) = self.games.remove(&key) {
+                result = result.and(self.record(&key.1, &game));
+            }
+        }
+        result
+    }
+
+    /// Saves the result of a daily puzzle on the leaderboard. Other games
+    /// aren't recorded.
+    fn record(&mut self, user: &str, game: &Game) -> Result<(), Error> {
+        match (game.mode, game.puzzle) {
+            (Mode::Daily, Some(puzzle)) => self.leaderboard.record(Record {
+                user: String::from(user),
+                puzzle,
+                guesses: game.history.len(),
+                won: game.won(),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Plays a message and returns the lines to post back to its channel.
+    pub(crate) fn handle(&mut self, message: &Message) -> Vec<String> {
+        let args = match message.text.trim().strip_prefix(PREFIX) {
+            Some(args) if args.is_empty() || args.starts_with(' ') => args.trim(),
+            _ => return vec![],
+        };
+        let key = (message.channel.clone(), message.user.clone());
+        if args == "/daily" {
+            // Taking the attempt up front keeps the puzzle from being
+            // restarted, here or on another channel, while it is played.
+            let puzzle = today_puzzle();
+            let game = Game::daily(self.dict).and_then(|game| {
+                self.leaderboard.start(&message.user, puzzle)?;
+                Ok(game)
+            });
+            match game {
+                Ok(game) => self.games.insert(key, (game, Instant::now())),
+                Err(e) => return vec![format!("{}: {}", message.user, e)],
+            };
+            return vec![format!(
+                "{}: puzzle #{} started, good luck!",
+                message.user, puzzle
+            )];
+        }
+        if let Some(period) = args.strip_prefix("/leaderboard") {
+            let period = match period.trim() {
+                "" => Ok(Period::Daily),
+                period => Period::from_str(period),
+            };
+            return match period {
+                Ok(period) => {
+                    let standings = self.leaderboard.standings(period, today_puzzle());
+                    leaderboard::render(&standings)
+                        .lines()
+                        .map(String::from)
+                        .collect()
+                }
+                Err(e) => vec![format!("{}: {}", message.user, e)],
+            };
+        }
+        if args.is_empty() {
+            return if self.games.contains_key(&key) {
+                vec![format!("{}: {}", message.user, HELP)]
+            } else {
+                self.games
+                    .insert(key, (Game::from(self.dict), Instant::now()));
+                vec![format!("{}: new game started! {}", message.user, HELP)]
+            };
+        }
+
+        let (game, last_message) = self
+            .games
+            .entry(key.clone())
+            .or_insert_with(|| (Game::from(self.dict), Instant::now()));
+        *last_message = Instant::now();
+        let mut output = vec![];
+        let dict = self.dict;
+        let result = Command::from_str(args).and_then(|command| {
+            let mut sink = Quiet(Writer::new(&mut output, Format::Text));
+            // There's no session to leave on a channel, so exiting gives up.
+            let command = match command {
+                Command::Exit => Command::GiveUp,
+                command => command,
+            };
+            game.eval(dict, &mut sink, command).map_err(Error::from)
+        });
+        if let Err(e) = result {
+            return vec![format!("{}: {}", message.user, e)];
+        }
+
+        let mut lines: Vec<String> = String::from_utf8_lossy(&output)
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| format!("{}: {}", message.user, line))
+            .collect();
+        if !game.keep_going {
+            lines.extend(game.share(Palette::Classic).lines().map(String::from));
+            if let Some((game, _)) = self.games.remove(&key) {
+                if let Err(e) = self.record(&message.user, &game) {
+                    lines.push(format!("{}: {}", message.user, e));
+                }
+            }
+        }
+        lines
+    }
+}
+
+/// Leaves out the help text of games started with `/new` and the analysis
+/// of finished games, which would flood the channel.
+struct Quiet<S: Sink>(S);
+
+impl<S: Sink> Sink for Quiet<S> {
+    fn event(&mut self, event: &Event) -> io::Result<()> {
+        match event {
+            Event::Start { .. } | Event::Analysis(_) => Ok(()),
+            event => self.0.event(event),
+        }
+    }
+}
+
+/// Connection to an IRC server.
+pub(crate) struct Irc {
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+    nick: String,
+}
+
+impl Irc {
+    /// Registers with the server and joins the channels. A nickname that is
+    /// already in use gets underscores appended until the server takes it.
+    pub(crate) fn connect<A: ToSocketAddrs>(
+        addr: A,
+        nick: &str,
+        channels: &[String],
+    ) -> Result<Self, Error> {
+        let stream = TcpStream::connect(addr)?;
+        let mut irc = Self {
+            reader: BufReader::new(stream.try_clone()?),
+            writer: stream,
+            nick: String::from(nick),
+        };
+        irc.raw(&format!("NICK {}", nick))?;
+        irc.raw(&format!("USER {} 0 * :wordler bot", nick))?;
+
+        // Wait for the welcome reply before joining.
+        let mut attempts = 1;
+        loop {
+            let line = irc.read_line()?.ok_or_else(|| {
+                Error::from(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "IRC server closed the connection during registration",
+                ))
+            })?;
+            let mut parts = line.split_whitespace();
+            if !parts.next().is_some_and(|p| p.starts_with(':')) {
+                continue;
+            }
+            match parts.next() {
+                Some("001") => break,
+                // The nickname is in use, or still held after a disconnect.
+                Some("433") | Some("437") => {
+                    if attempts == NICK_ATTEMPTS {
+                        return Err(Error::from(ErrorKind::NameTaken(irc.nick)));
+                    }
+                    attempts += 1;
+                    irc.nick.push('_');
+                    let nick = format!("NICK {}", irc.nick);
+                    irc.raw(&nick)?;
+                }
+                Some("432") => {
+                    return Err(Error::from(ErrorKind::InvalidArgument(format!(
+                        "the IRC server refused the nickname {}",
+                        irc.nick
+                    ))))
+                }
+                _ => {}
+            }
+        }
+        for channel in channels {
+            irc.raw(&format!("JOIN {}", channel))?;
+        }
+        Ok(irc)
+    }
+
+    fn raw(&mut self, line: &str) -> Result<(), Error> {
+        write!(self.writer, "{}\r\n", line)?;
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    /// Reads the next line from the server, answering pings along the way.
+    fn read_line(&mut self) -> Result<Option<String>, Error> {
+        loop {
+            let mut line = String::new();
+            if self.reader.read_line(&mut line)? == 0 {
+                return Ok(None);
+            }
+            let line = line.trim_end_matches(['\r', '\n']);
+            if let Some(token) = line.strip_prefix("PING ") {
+                self.raw(&format!("PONG {}", token))?;
+                continue;
+            }
+            return Ok(Some(String::from(line)));
+        }
+    }
+}
+
+/// Parses a `PRIVMSG` line. Private messages are answered privately, so
+/// their channel is the sender.
+fn parse_privmsg(line: &str, own_nick: &str) -> Option<Message> {
+    let rest = line.strip_prefix(':')?;
+    let (prefix, rest) = rest.split_once(' ')?;
+    let rest = rest.strip_prefix("PRIVMSG ")?;
+    let (target, text) = rest.split_once(" :")?;
+    let user = prefix.split('!').next()?;
+    let channel = if target == own_nick { user } else { target };
+    Some(Message {
+        channel: String::from(channel),
+        user: String::from(user),
+        text: String::from(text),
+    })
+}
+
+impl Transport for Irc {
+    fn recv(&mut self) -> Result<Option<Message>, Error> {
+        while let Some(line) = self.read_line()? {
+            if let Some(message) = parse_privmsg(&line, &self.nick) {
+                return Ok(Some(message));
+            }
+        }
+        Ok(None)
+    }
+
+    fn send(&mut self, channel: &str, text: &str) -> Result<(), Error> {
+        for line in text.lines() {
+            self.raw(&format!("PRIVMSG {} :{}", channel, line))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::word::fudge;
+    use std::{net::TcpListener, thread};
+
+    fn message(user: &str, text: &str) -> Message {
+        Message {
+            channel: String::from("#wordle"),
+            user: String::from(user),
+            text: String::from(text),
+        }
+    }
+
+    #[test]
+    fn test_games_per_user() {
+        let mut bot = Bot::new(fudge(), Leaderboard::in_memory());
+        assert!(bot.handle(&message("ana", "hello there")).is_empty());
+        assert!(bot.handle(&message("ana", "!wordlers")).is_empty());
+
+        assert!(bot.handle(&message("ana", "!wordle"))[0].starts_with("ana: new game started!"));
+        assert_eq!(
+            bot.handle(&message("ana", "!wordle reads")),
+            vec!["ana: ⬛🟨⬛🟨⬛"]
+        );
+        assert_eq!(
+            bot.handle(&message("bob", "!wordle lol")),
+            vec!["bob: guess too short, guesses must be 5 letters."]
+        );
+        assert_eq!(
+            bot.handle(&message("ana", "!wordle /nope")),
+            vec!["ana: unknown command /nope. use /help to list all available commands"]
+        );
+        assert_eq!(
+            bot.handle(&message("ana", "!wordle FUDGE")),
+            vec![
+                "ana: 🟩🟩🟩🟩🟩",
+                "ana: Congrats! 🎉",
+                "Wordler 2",
+                "⬛🟨⬛🟨⬛",
+                "🟩🟩🟩🟩🟩"
+            ]
+        );
+        assert!(!bot
+            .games
+            .contains_key(&(String::from("#wordle"), String::from("ana"))));
+        assert!(bot
+            .games
+            .contains_key(&(String::from("#wordle"), String::from("bob"))));
+
+        assert_eq!(
+            bot.handle(&message("bob", "!wordle /exit")),
+            vec!["bob: The word was FUDGE.", "Wordler X"]
+        );
+    }
+
+    #[test]
+    fn test_daily_leaderboard() {
+        let mut bot = Bot::new(fudge(), Leaderboard::in_memory());
+        let puzzle = today_puzzle();
+
+        assert_eq!(
+            bot.handle(&message("ana", "!wordle /daily")),
+            vec![format!("ana: puzzle #{} started, good luck!", puzzle)]
+        );
+        bot.handle(&message("ana", "!wordle reads"));
+        let lines = bot.handle(&message("ana", "!wordle fudge"));
+        assert_eq!(lines[2], format!("Wordler #{} 2", puzzle));
+
+        assert_eq!(
+            bot.handle(&message("ana", "!wordle /daily")),
+            vec![format!("ana: puzzle #{} has already been played.", puzzle)]
+        );
+
+        bot.handle(&message("bob", "!wordle /daily"));
+        let elsewhere = Message {
+            channel: String::from("#other"),
+            ..message("bob", "!wordle /daily")
+        };
+        assert_eq!(
+            bot.handle(&elsewhere),
+            vec![format!("bob: puzzle #{} has already been played.", puzzle)]
+        );
+        assert_eq!(
+            bot.handle(&message("bob", "!wordle /new")),
+            vec!["bob: /new isn't available in the daily puzzle."]
+        );
+        bot.handle(&message("bob", "!wordle /exit"));
+
+        // Outside the daily puzzle, /new gives up without the help text.
+        bot.handle(&message("dan", "!wordle reads"));
+        assert_eq!(
+            bot.handle(&message("dan", "!wordle /new")),
+            vec!["dan: The word was FUDGE."]
+        );
+
+        let board = bot.handle(&message("carl", "!wordle /leaderboard weekly"));
+        assert_eq!(board.len(), 3);
+        assert!(board[1].starts_with("1  ana             1/1       2.00"));
+        assert!(board[2].starts_with("2  bob             0/1       -"));
+        assert_eq!(
+            bot.handle(&message("carl", "!wordle /leaderboard yearly")),
+            vec!["carl: invalid argument: unknown period yearly, expected daily, weekly or all"]
+        );
+    }
+
+    #[test]
+    fn test_expiry() {
+        let mut bot = Bot::new(fudge(), Leaderboard::in_memory());
+        bot.handle(&message("ana", "!wordle /daily"));
+        bot.handle(&message("ana", "!wordle reads"));
+        bot.handle(&message("bob", "!wordle reads"));
+
+        bot.expire(Instant::now()).unwrap();
+        assert_eq!(bot.games.len(), 2);
+
+        // The abandoned daily puzzle is lost, and can't be started again.
+        bot.expire(Instant::now() + GAME_TTL).unwrap();
+        assert!(bot.games.is_empty());
+        let standings = bot.leaderboard.standings(Period::Daily, today_puzzle());
+        assert_eq!(standings.len(), 1);
+        assert_eq!((standings[0].played, standings[0].won), (1, 0));
+        assert!(bot.handle(&message("ana", "!wordle /daily"))[0].ends_with("already been played."));
+    }
+
+    /// Registers with a server that says the nickname is taken `taken` times,
+    /// and returns the nicknames the bot asked for.
+    fn register(taken: usize) -> (Result<Irc, Error>, Vec<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut writer = stream.try_clone().unwrap();
+            let mut lines = BufReader::new(stream).lines().map(|l| l.unwrap());
+            let mut nicks = vec![lines.next().unwrap()];
+            assert_eq!(lines.next().unwrap(), "USER wordler 0 * :wordler bot");
+            for _ in 0..taken {
+                write!(
+                    writer,
+                    ":irc.local 433 * wordler :Nickname is already in use\r\n"
+                )
+                .unwrap();
+                match lines.next() {
+                    Some(line) => nicks.push(line),
+                    None => return nicks,
+                }
+            }
+            write!(writer, ":irc.local 001 wordler :Welcome\r\n").unwrap();
+            nicks
+        });
+
+        let irc = Irc::connect(addr, "wordler", &[]);
+        (irc, server.join().unwrap())
+    }
+
+    #[test]
+    fn test_nick_in_use() {
+        let (irc, nicks) = register(1);
+        assert_eq!(nicks, ["NICK wordler", "NICK wordler_"]);
+        assert_eq!(irc.unwrap().nick, "wordler_");
+
+        let (irc, nicks) = register(NICK_ATTEMPTS);
+        assert_eq!(nicks.len(), NICK_ATTEMPTS);
+        assert_eq!(irc.err().unwrap().kind().code(), "name_taken");
+    }
+
+    #[test]
+    fn test_parse_privmsg() {
+        assert_eq!(
+            parse_privmsg(":ana!ana@host PRIVMSG #wordle :!wordle reads", "wordler"),
+            Some(message("ana", "!wordle reads"))
+        );
+        assert_eq!(
+            parse_privmsg(":ana!ana@host PRIVMSG wordler :!wordle", "wordler")
+                .unwrap()
+                .channel,
+            "ana"
+        );
+        assert_eq!(
+            parse_privmsg(":server 001 wordler :Welcome", "wordler"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_irc_server() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        // A tiny IRC server that checks the registration, sends two messages
+        // and hands back everything the bot said.
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut writer = stream.try_clone().unwrap();
+            let mut lines = BufReader::new(stream).lines().map(|l| l.unwrap());
+            assert_eq!(lines.next().unwrap(), "NICK wordler");
+            assert_eq!(lines.next().unwrap(), "USER wordler 0 * :wordler bot");
+            write!(writer, ":irc.local 001 wordler :Welcome\r\n").unwrap();
+            assert_eq!(lines.next().unwrap(), "JOIN #wordle");
+
+            write!(writer, "PING :irc.local\r\n").unwrap();
+            assert_eq!(lines.next().unwrap(), "PONG :irc.local");
+            write!(
+                writer,
+                ":ana!ana@host PRIVMSG #wordle :!wordle fudge\r\n:ana!ana@host PRIVMSG #wordle :just chatting\r\n"
+            )
+            .unwrap();
+            let replies: Vec<String> = lines.by_ref().take(4).collect();
+            drop(writer);
+            replies
+        });
+
+        let mut irc = Irc::connect(addr, "wordler", &[String::from("#wordle")]).unwrap();
+        Bot::new(fudge(), Leaderboard::in_memory())
+            .run(&mut irc)
+            .unwrap();
+
+        assert_eq!(
+            server.join().unwrap(),
+            vec![
+                "PRIVMSG #wordle :ana: 🟩🟩🟩🟩🟩",
+                "PRIVMSG #wordle :ana: Congrats! 🎉",
+                "PRIVMSG #wordle :Wordler 1",
+                "PRIVMSG #wordle :🟩🟩🟩🟩🟩",
+            ]
+        );
+    }
+}
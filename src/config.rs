@@ -0,0 +1,375 @@
+//! User configuration: defaults for the command line options, read from
+//! `$XDG_CONFIG_HOME/wordler/config.toml` (or `~/.config/wordler/config.toml`)
+//! and from `WORDLER_*` environment variables.
+//!
+//! Settings are applied in order, each overriding the previous one: the
+//! config file, the environment, then the command line. `WORDLER_CONFIG`
+//! points at another config file.
+//!
+//! Only the part of TOML a flat list of settings needs is understood:
+//! `key = value` lines with strings, integers and booleans, and comments.
+//!
+//! ```toml
+//! hard = true
+//! palette = "high-contrast"
+//! max_guesses = 6
+//! dict = "/usr/share/wordler/team.txt"
+//! ```
+
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use crate::{
+    difficulty::Difficulty, event::Format, lang::Language, palette::Palette, Args, Error, ErrorKind,
+};
+
+/// Settings that can be given a default, with the environment variable each
+/// one is read from.
+const SETTINGS: [(&str, &str); 8] = [
+    ("hard", "WORDLER_HARD"),
+    ("palette", "WORDLER_PALETTE"),
+    ("length", "WORDLER_LENGTH"),
+    ("max_guesses", "WORDLER_MAX_GUESSES"),
+    ("dict", "WORDLER_DICT"),
+    ("format", "WORDLER_FORMAT"),
+    ("lang", "WORDLER_LANG"),
+    ("difficulty", "WORDLER_DIFFICULTY"),
+];
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+enum Value {
+    String(String),
+    Integer(i64),
+    Boolean(bool),
+}
+
+#[derive(PartialEq, Eq, Debug, Default)]
+pub(crate) struct Config {
+    pub(crate) hard: Option<bool>,
+    pub(crate) palette: Option<Palette>,
+    pub(crate) length: Option<usize>,
+    pub(crate) max_guesses: Option<usize>,
+    pub(crate) dict: Option<String>,
+    pub(crate) format: Option<Format>,
+    pub(crate) lang: Option<&'static Language>,
+    pub(crate) difficulty: Option<Difficulty>,
+}
+
+impl Config {
+    /// Reads the config file, if there is one, and the environment.
+    pub(crate) fn load() -> Result<Self, Error> {
+        let path = env::var_os("WORDLER_CONFIG")
+            .filter(|path| !path.is_empty())
+            .map(PathBuf::from)
+            .or_else(default_path);
+        let mut config = match path {
+            Some(path) => Self::open(&path)?,
+            None => Self::default(),
+        };
+        config.merge_env(env::vars())?;
+        Ok(config)
+    }
+
+    /// Reads a config file, which doesn't have to exist.
+    pub(crate) fn open(path: &Path) -> Result<Self, Error> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|msg| {
+                Error::from(ErrorKind::InvalidConfig(format!(
+                    "{}:{}",
+                    path.display(),
+                    msg
+                )))
+            }),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(Error::from(e)),
+        }
+    }
+
+    /// Parses a config file. Errors start with the line number.
+    fn parse(text: &str) -> Result<Self, String> {
+        let mut config = Self::default();
+        let mut seen = vec![];
+        for (n, line) in text.lines().enumerate() {
+            let line = strip_comment(line).trim();
+            if line.is_empty() {
+                continue;
+            }
+            let error = |msg: String| format!("{}: {}", n + 1, msg);
+
+            if line.starts_with('[') {
+                return Err(error(String::from("tables aren't supported")));
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| error(format!("expected key = value, found {}", line)))?;
+            let key = key.trim();
+            if seen.contains(&key) {
+                return Err(error(format!("{} is set twice", key)));
+            }
+            seen.push(key);
+            let value = parse_value(value.trim()).map_err(error)?;
+            config.set(key, value).map_err(error)?;
+        }
+        Ok(config)
+    }
+
+    /// Overrides settings with the `WORDLER_*` variables that are set.
+    fn merge_env<I: Iterator<Item = (String, String)>>(&mut self, vars: I) -> Result<(), Error> {
+        for (var, raw) in vars {
+            let Some((key, _)) = SETTINGS.iter().find(|(_, name)| *name == var) else {
+                continue;
+            };
+            if raw.is_empty() {
+                continue;
+            }
+            let value = match *key {
+                "hard" => match raw.as_str() {
+                    "true" | "1" => Value::Boolean(true),
+                    "false" | "0" => Value::Boolean(false),
+                    _ => Value::String(raw),
+                },
+                "length" | "max_guesses" => raw
+                    .parse()
+                    .map(Value::Integer)
+                    .unwrap_or(Value::String(raw)),
+                _ => Value::String(raw),
+            };
+            self.set(key, value).map_err(|msg| {
+                Error::from(ErrorKind::InvalidConfig(format!("{}: {}", var, msg)))
+            })?;
+        }
+        Ok(())
+    }
+
+    fn set(&mut self, key: &str, value: Value) -> Result<(), String> {
+        match (key, value) {
+            ("hard", Value::Boolean(hard)) => self.hard = Some(hard),
+            ("length", Value::Integer(n)) => self.length = Some(count(key, n)?),
+            ("max_guesses", Value::Integer(n)) => self.max_guesses = Some(count(key, n)?),
+            ("dict", Value::String(path)) => self.dict = Some(path),
+            ("palette", Value::String(s)) => self.palette = Some(setting(&s)?),
+            ("format", Value::String(s)) => self.format = Some(setting(&s)?),
+            ("lang", Value::String(s)) => self.lang = Some(setting(&s)?),
+            ("difficulty", Value::String(s)) => self.difficulty = Some(setting(&s)?),
+            ("hard", _) => return Err(String::from("hard must be true or false")),
+            ("length" | "max_guesses", _) => return Err(format!("{} must be a number", key)),
+            (key, _) if SETTINGS.iter().any(|(name, _)| *name == key) => {
+                return Err(format!("{} must be a string", key))
+            }
+            (key, _) => {
+                let keys: Vec<&str> = SETTINGS.iter().map(|(name, _)| *name).collect();
+                return Err(format!(
+                    "unknown setting {}, expected one of {}",
+                    key,
+                    keys.join(", ")
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// The arguments to start parsing the command line with, so flags given
+    /// there override these defaults.
+    pub(crate) fn args(&self) -> Args {
+        Args {
+            hard: self.hard.unwrap_or_default(),
+            palette: self.palette.unwrap_or_default(),
+            length: self.length,
+            max_guesses: self.max_guesses,
+            dict: self.dict.clone(),
+            format: self.format.unwrap_or_default(),
+            lang: self.lang,
+            difficulty: self.difficulty,
+            ..Args::default()
+        }
+    }
+}
+
+/// `$XDG_CONFIG_HOME/wordler/config.toml`, or `~/.config/wordler/config.toml`
+/// if it isn't set.
+fn default_path() -> Option<PathBuf> {
+    let config = match env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => PathBuf::from(env::var_os("HOME")?).join(".config"),
+    };
+    Some(config.join("wordler").join("config.toml"))
+}
+
+/// Parses a setting with the `FromStr` implementation the matching command
+/// line option uses, keeping just its message.
+fn setting<T: FromStr<Err = Error>>(s: &str) -> Result<T, String> {
+    T::from_str(s).map_err(|e| match e.kind {
+        ErrorKind::InvalidArgument(msg) => msg,
+        kind => Error::from(kind).to_string(),
+    })
+}
+
+fn count(key: &str, n: i64) -> Result<usize, String> {
+    usize::try_from(n)
+        .ok()
+        .filter(|&n| n > 0)
+        .ok_or_else(|| format!("{} must be at least 1", key))
+}
+
+/// Removes a `#` comment, unless the `#` is inside a string.
+fn strip_comment(line: &str) -> &str {
+    let mut quote = None;
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        match (quote, c) {
+            (Some('"'), '\\') if !escaped => {
+                escaped = true;
+                continue;
+            }
+            (Some(q), c) if c == q && !escaped => quote = None,
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '#') => return &line[..i],
+            _ => {}
+        }
+        escaped = false;
+    }
+    line
+}
+
+fn parse_value(value: &str) -> Result<Value, String> {
+    if let Some(literal) = value.strip_prefix('\'') {
+        return literal
+            .strip_suffix('\'')
+            .filter(|s| !s.contains('\''))
+            .map(|s| Value::String(String::from(s)))
+            .ok_or_else(|| format!("unterminated string {}", value));
+    }
+    if let Some(basic) = value.strip_prefix('"') {
+        let mut out = String::new();
+        let mut chars = basic.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '"' if chars.as_str().is_empty() => return Ok(Value::String(out)),
+                '"' => return Err(format!("unexpected text after string {}", value)),
+                '\\' => match chars.next() {
+                    Some('"') => out.push('"'),
+                    Some('\\') => out.push('\\'),
+                    Some('n') => out.push('\n'),
+                    Some('t') => out.push('\t'),
+                    other => {
+                        return Err(format!(
+                            "unsupported escape \\{}",
+                            other.map(String::from).unwrap_or_default()
+                        ))
+                    }
+                },
+                c => out.push(c),
+            }
+        }
+        return Err(format!("unterminated string {}", value));
+    }
+    match value {
+        "true" => Ok(Value::Boolean(true)),
+        "false" => Ok(Value::Boolean(false)),
+        number => number
+            .replace('_', "")
+            .parse()
+            .map(Value::Integer)
+            .map_err(|_| format!("invalid value {}", value)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::lang::GERMAN;
+
+    #[test]
+    fn test_parse() {
+        let config = Config::parse(
+            "# team defaults\n\
+             hard = true\n\
+             palette = \"high-contrast\" # easier to read\n\
+             \n\
+             max_guesses = 1_0\n\
+             dict = 'C:\\words\\#team.txt'\n\
+             lang = \"de\"\n",
+        )
+        .unwrap();
+        assert_eq!(
+            config,
+            Config {
+                hard: Some(true),
+                palette: Some(Palette::HighContrast),
+                max_guesses: Some(10),
+                dict: Some(String::from("C:\\words\\#team.txt")),
+                lang: Some(&GERMAN),
+                ..Config::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |text: &str| Config::parse(text).unwrap_err();
+        assert_eq!(error("hard = yes"), "1: invalid value yes");
+        assert_eq!(error("\nhard = 1"), "2: hard must be true or false");
+        assert_eq!(error("length = \"5\""), "1: length must be a number");
+        assert_eq!(
+            error("max_guesses = 0"),
+            "1: max_guesses must be at least 1"
+        );
+        assert_eq!(error("hard = true\nhard = false"), "2: hard is set twice");
+        assert_eq!(error("[play]"), "1: tables aren't supported");
+        assert_eq!(
+            error("dict = \"words.txt"),
+            "1: unterminated string \"words.txt"
+        );
+        assert!(error("colour = \"red\"").starts_with("1: unknown setting colour"));
+        assert!(error("palette = \"neon\"").contains("unknown palette neon"));
+    }
+
+    #[test]
+    fn test_precedence() {
+        let mut config = Config::parse("hard = true\nlength = 6\nformat = \"json\"").unwrap();
+        let vars = [
+            ("WORDLER_LENGTH", "7"),
+            ("WORDLER_HARD", "0"),
+            ("WORDLER_FORMAT", ""),
+            ("HOME", "/home/ana"),
+        ];
+        config
+            .merge_env(vars.iter().map(|(k, v)| (k.to_string(), v.to_string())))
+            .unwrap();
+        assert_eq!(config.hard, Some(false));
+        assert_eq!(config.length, Some(7));
+        assert_eq!(config.format, Some(Format::Json));
+
+        let cli = |a: &[&str]| Args::parse(config.args(), a.iter().map(|s| s.to_string()));
+        assert_eq!(cli(&[]).unwrap().length, Some(7));
+        assert_eq!(cli(&["--length", "5"]).unwrap().length, Some(5));
+        assert!(cli(&["--hard"]).unwrap().hard);
+        assert_eq!(cli(&["--format", "text"]).unwrap().format, Format::Text);
+
+        let bad = [(String::from("WORDLER_MAX_GUESSES"), String::from("six"))];
+        let e = config.merge_env(bad.into_iter()).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "invalid config: WORDLER_MAX_GUESSES: max_guesses must be a number"
+        );
+    }
+
+    #[test]
+    fn test_open() {
+        let dir = env::temp_dir().join(format!("wordler-config-{}", std::process::id()));
+        let path = dir.join("config.toml");
+        assert_eq!(Config::open(&path).unwrap(), Config::default());
+
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(&path, "hard = true\nlength = x\n").unwrap();
+        let e = Config::open(&path).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            format!("invalid config: {}:2: invalid value x", path.display())
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
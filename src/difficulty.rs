@@ -0,0 +1,311 @@
+//! Difficulty ratings for answer words.
+//!
+//! A word is harder when its letters are rare in the word list, when it
+//! repeats letters, when many other words differ from it by a single letter
+//! (the "trap" of `fight`, `light`, `might`, ...), and when a simple solver
+//! needs more guesses to find it. Words are ranked by the sum of these and
+//! split into three equally sized bands.
+
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use crate::{
+    word::{Word, WordList},
+    Error, ErrorKind,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum Difficulty {
+    Easy,
+    Medium,
+    Hard,
+}
+
+impl FromStr for Difficulty {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "easy" => Ok(Difficulty::Easy),
+            "medium" => Ok(Difficulty::Medium),
+            "hard" => Ok(Difficulty::Hard),
+            other => Err(Error::from(ErrorKind::InvalidArgument(format!(
+                "unknown difficulty {}, expected easy, medium or hard",
+                other
+            )))),
+        }
+    }
+}
+
+impl Difficulty {
+    /// The name given to `--difficulty`.
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Difficulty::Easy => "easy",
+            Difficulty::Medium => "medium",
+            Difficulty::Hard => "hard",
+        }
+    }
+
+    /// The words of `dict` in this difficulty band, which is empty when the
+    /// list is too small to split in three.
+    pub(crate) fn answers(self, dict: &WordList) -> Result<WordList, Error> {
+        let ratings = rate(dict);
+        let third = ratings.len().div_ceil(3);
+        let band = match self {
+            Difficulty::Easy => 0,
+            Difficulty::Medium => 1,
+            Difficulty::Hard => 2,
+        };
+        let words: HashSet<Word> = ratings
+            .iter()
+            .skip(band * third)
+            .take(third)
+            .map(|r| r.word)
+            .collect();
+        if words.is_empty() {
+            return Err(Error::from(ErrorKind::InvalidArgument(format!(
+                "no {} words, the word list is too small to split into difficulties",
+                self.name()
+            ))));
+        }
+        Ok(dict.filter(|w| words.contains(w)))
+    }
+}
+
+/// What makes a word easy or hard to guess.
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) struct Rating {
+    pub(crate) word: Word,
+
+    /// How uncommon the word's letters are, from 0 when every letter is the
+    /// most common one to 1.
+    pub(crate) rarity: f64,
+
+    /// Letters that appear more than once.
+    pub(crate) duplicates: usize,
+
+    /// Other words that differ from this one by a single letter.
+    pub(crate) traps: usize,
+
+    /// Guesses the solver needs to find the word.
+    pub(crate) guesses: usize,
+}
+
+impl Rating {
+    pub(crate) fn score(&self) -> f64 {
+        3.0 * self.rarity
+            + self.duplicates as f64
+            + (self.traps as f64).ln_1p()
+            + self.guesses as f64
+    }
+}
+
+/// Rates every word of the list, from the easiest to the hardest.
+pub(crate) fn rate(dict: &WordList) -> Vec<Rating> {
+    let frequencies = frequencies(dict);
+    let most_common = frequencies.values().copied().max().unwrap_or(1) as f64;
+
+    // Words sharing a pattern with one letter blanked out trap each other.
+    let blanks: Vec<Vec<(usize, Vec<char>)>> = dict
+        .iter()
+        .map(|word| {
+            (0..word.letters().count())
+                .map(|blank| blanked(&word, blank))
+                .collect()
+        })
+        .collect();
+    let mut patterns: HashMap<&(usize, Vec<char>), usize> = HashMap::new();
+    for pattern in blanks.iter().flatten() {
+        *patterns.entry(pattern).or_default() += 1;
+    }
+
+    let guesses = solve(dict, &frequencies);
+
+    let mut ratings: Vec<Rating> = dict
+        .iter()
+        .zip(&blanks)
+        .map(|(word, blanks)| {
+            let letters: Vec<char> = word.letters().collect();
+            let rarity = letters
+                .iter()
+                .map(|l| 1.0 - frequencies[l] as f64 / most_common)
+                .sum::<f64>()
+                / letters.len() as f64;
+            let mut distinct = letters.clone();
+            distinct.sort_unstable();
+            distinct.dedup();
+            let traps = blanks.iter().map(|pattern| patterns[pattern] - 1).sum();
+
+            Rating {
+                word,
+                rarity,
+                duplicates: letters.len() - distinct.len(),
+                traps,
+                guesses: guesses[&word],
+            }
+        })
+        .collect();
+    ratings.sort_by(|a, b| a.score().total_cmp(&b.score()).then(a.word.cmp(&b.word)));
+    ratings
+}
+
+fn blanked(word: &Word, blank: usize) -> (usize, Vec<char>) {
+    let letters = word
+        .letters()
+        .enumerate()
+        .filter(|(i, _)| *i != blank)
+        .map(|(_, l)| l)
+        .collect();
+    (blank, letters)
+}
+
+/// How many words of the list each letter appears in, counting repeats.
+fn frequencies(dict: &WordList) -> HashMap<char, usize> {
+    let mut frequencies: HashMap<char, usize> = HashMap::new();
+    for word in dict.iter() {
+        for letter in word.letters() {
+            *frequencies.entry(letter).or_default() += 1;
+        }
+    }
+    frequencies
+}
+
+/// The words of the list in the order the solver prefers them: the ones
+/// covering the most common letters first.
+fn by_coverage(dict: &WordList, frequencies: &HashMap<char, usize>) -> Vec<Word> {
+    let coverage = |word: &Word| -> usize {
+        let mut letters: Vec<char> = word.letters().collect();
+        letters.sort_unstable();
+        letters.dedup();
+        letters.iter().map(|l| frequencies[l]).sum()
+    };
+    let mut candidates: Vec<Word> = dict.iter().collect();
+    candidates.sort_by_cached_key(|w| std::cmp::Reverse(coverage(w)));
+    candidates
+}
+
+/// The words of the list in the order the solver guesses them.
+pub(crate) fn ranked(dict: &WordList) -> Vec<Word> {
+    by_coverage(dict, &frequencies(dict))
+}
+
+/// The guesses the solver makes to find `answer`, ending with the answer
+/// itself if it is in the list.
+pub(crate) fn guesses(dict: &WordList, answer: &Word) -> Vec<Word> {
+    let mut candidates = ranked(dict);
+    let mut guesses = vec![];
+    while let Some(&guess) = candidates.first() {
+        guesses.push(guess);
+        if guess == *answer {
+            break;
+        }
+        let feedback = guess.score(answer).pattern();
+        candidates.retain(|c| *c != guess && guess.score(c).pattern() == feedback);
+    }
+    guesses
+}
+
+/// Plays every word of the list with a greedy solver that always guesses the
+/// remaining candidate covering the most common letters, and returns how
+/// many guesses each word took.
+fn solve(dict: &WordList, frequencies: &HashMap<char, usize>) -> HashMap<Word, usize> {
+    let candidates = by_coverage(dict, frequencies);
+
+    // Candidates that give the same feedback stay together and are played
+    // out as a group, which walks the solver's whole decision tree once.
+    let mut guesses = HashMap::new();
+    let mut groups = vec![(candidates, 1)];
+    while let Some((candidates, depth)) = groups.pop() {
+        let Some((guess, rest)) = candidates.split_first() else {
+            continue;
+        };
+        guesses.insert(*guess, depth);
+
+        let mut feedback: HashMap<String, Vec<Word>> = HashMap::new();
+        for answer in rest {
+            feedback
+                .entry(guess.score(answer).pattern())
+                .or_default()
+                .push(*answer);
+        }
+        groups.extend(feedback.into_values().map(|group| (group, depth + 1)));
+    }
+    guesses
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const DICT: &str =
+        "fight\nlight\nmight\nnight\nright\nsight\ntight\nfudge\nreads\nsassy\nbread";
+
+    fn rating<'a>(ratings: &'a [Rating], word: &str) -> &'a Rating {
+        ratings.iter().find(|r| r.word.to_string() == word).unwrap()
+    }
+
+    #[test]
+    fn test_rate() {
+        let dict = WordList::from(DICT);
+        let ratings = rate(&dict);
+        assert_eq!(ratings.len(), dict.len());
+        assert!(ratings.windows(2).all(|r| r[0].score() <= r[1].score()));
+
+        assert_eq!(rating(&ratings, "light").traps, 6);
+        assert_eq!(rating(&ratings, "fudge").traps, 0);
+        assert_eq!(rating(&ratings, "sassy").duplicates, 2);
+        assert_eq!(rating(&ratings, "reads").duplicates, 0);
+
+        // Every word is eventually found, and only one in a single guess.
+        assert_eq!(ratings.iter().filter(|r| r.guesses == 1).count(), 1);
+        assert!(ratings.iter().all(|r| r.guesses >= 1));
+        assert!(rating(&ratings, "tight").guesses > rating(&ratings, "fudge").guesses);
+    }
+
+    #[test]
+    fn test_guesses() {
+        let dict = WordList::from(DICT);
+        let ratings = rate(&dict);
+        for rating in &ratings {
+            let guesses = guesses(&dict, &rating.word);
+            assert_eq!(guesses.len(), rating.guesses);
+            assert_eq!(guesses.last(), Some(&rating.word));
+        }
+    }
+
+    #[test]
+    fn test_bands() {
+        let dict = WordList::from(DICT);
+        let easy = Difficulty::Easy.answers(&dict).unwrap();
+        let medium = Difficulty::Medium.answers(&dict).unwrap();
+        let hard = Difficulty::Hard.answers(&dict).unwrap();
+        assert_eq!(easy.len() + medium.len() + hard.len(), dict.len());
+        assert!(easy.iter().all(|w| !medium.contains(&w.to_string())));
+        assert!(hard.iter().all(|w| !medium.contains(&w.to_string())));
+        assert!(easy.contains("reads"));
+        assert!(hard.contains("night"));
+        assert!(hard.contains("tight"));
+        assert_eq!(easy.language(), dict.language());
+
+        // Two words can't make three bands.
+        let dict = WordList::from("fudge\nreads");
+        assert!(Difficulty::Easy.answers(&dict).is_ok());
+        let e = Difficulty::Hard.answers(&dict).unwrap_err();
+        assert_eq!(e.kind().code(), "invalid_argument");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(Difficulty::from_str("hard").unwrap(), Difficulty::Hard);
+        assert!(Difficulty::from_str("nightmare").is_err());
+    }
+
+    #[test]
+    fn test_rate_english() {
+        let ratings = rate(crate::ENGLISH.words());
+        assert!(ratings.iter().all(|r| r.guesses <= 20));
+    }
+}
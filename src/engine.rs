@@ -0,0 +1,209 @@
+//! Line protocol for driving wordler from bots and graphical front ends.
+//!
+//! The engine reads one command per line on stdin and answers with exactly one
+//! line on stdout, except for `quit` which exits silently. There is no banner
+//! and no prompt. Responses start with a keyword followed by `key=value`
+//! fields separated by spaces; values never contain spaces, except `message`
+//! which is always the last field and runs to the end of the line.
+//!
+//! Commands:
+//!
+//! ```text
+//! newgame [seed]   start a new game, with a deterministic word when seeded
+//!                  -> ok length=5
+//! guess <word>     submit a guess for the current game
+//!                  -> result word=reads pattern=01010 status=playing guesses=1
+//! state            describe the current game
+//!                  -> state status=won guesses=3 length=5 word=fudge
+//! quit             exit the engine
+//! ```
+//!
+//! Patterns have one digit per letter: `0` when the letter is absent from the
+//! word, `1` when it is present elsewhere and `2` when it is in the right
+//! spot. `status` is `playing` or `won`, and the secret `word` is only
+//! included once the game has been won.
+//!
+//! Any command can fail with `error code=<code> message=<text>`, where `code`
+//! is one of the stable error codes also used by the JSON output format.
+
+use std::io::{self, BufRead, Write};
+
+use crate::{word::WordList, Error, ErrorKind, Game};
+
+/// Plays games on behalf of a remote front end.
+pub(crate) struct Engine<'a> {
+    dict: &'a WordList,
+    game: Option<Game>,
+    running: bool,
+}
+
+impl<'a> Engine<'a> {
+    pub(crate) fn new(dict: &'a WordList) -> Self {
+        Self {
+            dict,
+            game: None,
+            running: true,
+        }
+    }
+
+    /// Runs the engine over stdin and stdout until `quit` or end of input.
+    pub(crate) fn run(mut self) -> Result<(), Error> {
+        let stdin = io::stdin();
+        let mut stdout = io::stdout();
+        for line in stdin.lock().lines() {
+            if let Some(response) = self.exec(&line?) {
+                writeln!(stdout, "{}", response)?;
+                stdout.flush()?;
+            }
+            if !self.running {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Executes a single protocol line and returns the response to send back,
+    /// if any.
+    pub(crate) fn exec(&mut self, line: &str) -> Option<String> {
+        let mut parts = line.split_whitespace();
+        let response = match (parts.next(), parts.next(), parts.next()) {
+            (None, _, _) => return None,
+            (Some("quit"), None, _) => {
+                self.running = false;
+                return None;
+            }
+            (Some("newgame"), seed, None) => self.newgame(seed),
+            (Some("guess"), Some(word), None) => self.guess(word),
+            (Some("state"), None, _) => self.state(),
+            _ => Err(Error::from(ErrorKind::InvalidCommand)),
+        };
+
+        Some(response.unwrap_or_else(|e| format!("error code={} message={}", e.kind().code(), e)))
+    }
+
+    fn newgame(&mut self, seed: Option<&str>) -> Result<String, Error> {
+        let game = match seed {
+            Some(seed) => {
+                let seed = seed.parse().map_err(|_| {
+                    Error::from(ErrorKind::InvalidArgument(format!(
+                        "seed must be a number, got {}",
+                        seed
+                    )))
+                })?;
+                Game::with_seed(self.dict, seed)
+            }
+            None => Game::from(self.dict),
+        };
+        let response = format!("ok length={}", game.word.chars().count());
+        self.game = Some(game);
+        Ok(response)
+    }
+
+    fn guess(&mut self, word: &str) -> Result<String, Error> {
+        let game = self.game.as_mut().ok_or(ErrorKind::NoGame)?;
+        if !game.keep_going {
+            return Err(Error::from(ErrorKind::GameOver));
+        }
+
+        let guess = game.guess(String::from(word))?;
+        if guess.correct() {
+            game.keep_going = false;
+        }
+
+        Ok(format!(
+            "result word={} pattern={} status={} guesses={}",
+            game.language.fold(word),
+            guess.pattern(),
+            status(game),
+            game.history.len()
+        ))
+    }
+
+    fn state(&self) -> Result<String, Error> {
+        let game = self.game.as_ref().ok_or(ErrorKind::NoGame)?;
+        let mut response = format!(
+            "state status={} guesses={} length={}",
+            status(game),
+            game.history.len(),
+            game.word.chars().count()
+        );
+        if !game.keep_going {
+            response.push_str(&format!(" word={}", game.word));
+        }
+        Ok(response)
+    }
+}
+
+fn status(game: &Game) -> &'static str {
+    if game.keep_going {
+        "playing"
+    } else {
+        "won"
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::word::fudge;
+
+    #[test]
+    fn test_engine_session() {
+        let mut engine = Engine::new(fudge());
+
+        assert_eq!(
+            engine.exec("guess fudge").unwrap(),
+            "error code=no_game message=no game in progress, start one with newgame."
+        );
+        assert_eq!(engine.exec("newgame").unwrap(), "ok length=5");
+        assert_eq!(
+            engine.exec("state").unwrap(),
+            "state status=playing guesses=0 length=5"
+        );
+        assert_eq!(
+            engine.exec("guess READS").unwrap(),
+            "result word=reads pattern=01010 status=playing guesses=1"
+        );
+        assert!(engine
+            .exec("guess lol")
+            .unwrap()
+            .starts_with("error code=guess_too_short "));
+        assert_eq!(
+            engine.exec("guess fudge").unwrap(),
+            "result word=fudge pattern=22222 status=won guesses=2"
+        );
+        assert!(engine
+            .exec("guess fudge")
+            .unwrap()
+            .starts_with("error code=game_over "));
+        assert_eq!(
+            engine.exec("state").unwrap(),
+            "state status=won guesses=2 length=5 word=fudge"
+        );
+
+        assert_eq!(engine.exec(""), None);
+        assert!(engine
+            .exec("dance")
+            .unwrap()
+            .starts_with("error code=invalid_command "));
+        assert_eq!(engine.exec("quit"), None);
+        assert!(!engine.running);
+    }
+
+    #[test]
+    fn test_engine_seed() {
+        let mut first = Engine::new(crate::ENGLISH.words());
+        let mut second = Engine::new(crate::ENGLISH.words());
+        first.exec("newgame 42");
+        second.exec("newgame 42");
+        assert_eq!(
+            first.game.as_ref().unwrap().word,
+            second.game.as_ref().unwrap().word
+        );
+
+        assert!(first
+            .exec("newgame abc")
+            .unwrap()
+            .starts_with("error code=invalid_argument "));
+    }
+}
@@ -0,0 +1,470 @@
+//! Events emitted while playing, and how they are rendered for each output
+//! format.
+
+use std::{
+    io::{self, Write},
+    str::FromStr,
+};
+
+use crate::{analysis::Report, json, palette::Palette, Error, ErrorKind, Game, Guess, Tally};
+
+/// The help text for a game whose word has `length` letters.
+pub(crate) fn help(length: usize) -> String {
+    format!(
+        "Welcome to Wordler!
+A Wordle REPL thingy. Can you guess the {}-letter word?
+
+COMMANDS:
+\t/help\tPrints this help text.
+\t/letters\tShows the letters that have not been tried yet.
+\t/board\tShows every guess so far.
+\t/new [length]\tGives up and starts a new game, optionally with another word length.
+\t/giveup\tGives up and reveals the word.
+\t/stats\tShows the games played in this session.
+\t/share\tShows a spoiler-free summary of the game.
+\t/hard [on|off]\tTurns hard mode on or off.
+\t/hint\tReveals a letter and how many words still fit.
+\t/undo\tTakes back the last guess, in practice games only.
+\t/exit\tExits the game.",
+        length
+    )
+}
+
+/// Every REPL command, also used to suggest one for a mistyped command.
+pub(crate) const COMMANDS: [&str; 11] = [
+    "/help", "/letters", "/board", "/new", "/giveup", "/stats", "/share", "/hard", "/hint",
+    "/undo", "/exit",
+];
+
+/// Output format of the REPL.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub(crate) enum Format {
+    /// Human readable text with emoji feedback.
+    #[default]
+    Text,
+
+    /// One JSON object per line, for driving wordler from other tools.
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            other => Err(Error::from(ErrorKind::InvalidArgument(format!(
+                "unknown format {}, expected text or json",
+                other
+            )))),
+        }
+    }
+}
+
+/// Something that happened during a game.
+pub(crate) enum Event<'a> {
+    /// A new game started.
+    Start {
+        length: usize,
+        practice: bool,
+        puzzle: Option<u64>,
+    },
+    /// The player asked for the help text, for a word of `length` letters.
+    Help { length: usize },
+    /// The letters that have not been tried yet, sorted.
+    Letters(&'a [char]),
+    /// Feedback for a valid guess.
+    Guess { word: &'a str, guess: &'a Guess },
+    /// Every guess so far, with its feedback.
+    Board(&'a [(String, Guess)]),
+    /// The word, shown when the player gives up or runs out of guesses.
+    Reveal(&'a str),
+    /// The games played in this session.
+    Stats(&'a Tally),
+    /// A spoiler-free summary of the game.
+    Share(&'a Game),
+    /// Hard mode was turned on or off.
+    Hard(bool),
+    /// How many words still fit the feedback, and a letter of the word.
+    Hint {
+        candidates: usize,
+        position: usize,
+        letter: char,
+    },
+    /// The last guess was taken back, leaving this many.
+    Undo { word: &'a str, guesses: usize },
+    /// How each guess of a game that just ended fared.
+    Analysis(&'a [Report]),
+    /// A command or guess was rejected.
+    Error(&'a Error),
+    /// The game ended, either by guessing the word or giving up.
+    Outcome {
+        won: bool,
+        word: &'a str,
+        guesses: usize,
+    },
+}
+
+impl Event<'_> {
+    /// Renders the event as a single output line, or `None` if there is
+    /// nothing to show for it in this format.
+    pub(crate) fn render(&self, format: Format, palette: Palette) -> Option<String> {
+        match format {
+            Format::Text => self.text(palette),
+            Format::Json => Some(self.json()),
+        }
+    }
+
+    fn text(&self, palette: Palette) -> Option<String> {
+        match self {
+            Event::Start {
+                length, practice, ..
+            } => {
+                if *practice {
+                    Some(format!(
+                        "{}\n\nThis is a practice game, it won't count toward your stats.",
+                        help(*length)
+                    ))
+                } else {
+                    Some(help(*length))
+                }
+            }
+            Event::Help { length } => Some(help(*length)),
+            Event::Letters(letters) => Some(
+                letters
+                    .iter()
+                    .map(|l| l.to_string())
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            ),
+            Event::Guess { guess, .. } => Some(palette.squares(guess)),
+            Event::Board([]) => Some(String::from("No guesses yet.")),
+            Event::Board(history) => Some(
+                history
+                    .iter()
+                    .map(|(word, guess)| format!("{} {}", palette.squares(guess), word))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            ),
+            Event::Reveal(word) => Some(format!("The word was {}.", word.to_uppercase())),
+            Event::Stats(tally) => Some(format!(
+                "Played {}, won {}, current streak {}, max streak {}.",
+                tally.played, tally.won, tally.streak, tally.max_streak
+            )),
+            Event::Share(game) => Some(game.share(palette)),
+            Event::Hard(on) => Some(format!("Hard mode is {}.", if *on { "on" } else { "off" })),
+            Event::Hint {
+                candidates,
+                position,
+                letter,
+            } => Some(format!(
+                "{} {}. Letter {} is {}.",
+                candidates,
+                if *candidates == 1 {
+                    "word still fits"
+                } else {
+                    "words still fit"
+                },
+                position,
+                letter.to_uppercase()
+            )),
+            Event::Undo { word, .. } => Some(format!("Took back {}.", word.to_uppercase())),
+            Event::Analysis(reports) => Some(analysis_text(reports)),
+            Event::Error(e) => Some(e.to_string()),
+            Event::Outcome { won: true, .. } => Some(String::from("Congrats! 🎉")),
+            Event::Outcome { won: false, .. } => None,
+        }
+    }
+
+    fn json(&self) -> String {
+        match self {
+            Event::Start {
+                length,
+                practice,
+                puzzle,
+            } => json::Object::new()
+                .str("event", "start")
+                .num("length", length)
+                .bool("practice", *practice)
+                .opt_num("puzzle", *puzzle)
+                .finish(),
+            Event::Help { .. } => json::Object::new()
+                .str("event", "help")
+                .raw(
+                    "commands",
+                    json::array(COMMANDS.iter().map(|c| json::string(c))),
+                )
+                .finish(),
+            Event::Letters(letters) => json::Object::new()
+                .str("event", "letters")
+                .raw(
+                    "unused",
+                    json::array(letters.iter().map(|l| json::string(&l.to_string()))),
+                )
+                .finish(),
+            Event::Guess { word, guess } => json::Object::new()
+                .str("event", "guess")
+                .str("word", word)
+                .raw("letters", letters_json(word, guess))
+                .bool("correct", guess.correct())
+                .finish(),
+            Event::Board(history) => json::Object::new()
+                .str("event", "board")
+                .raw(
+                    "guesses",
+                    json::array(history.iter().map(|(word, guess)| {
+                        json::Object::new()
+                            .str("word", word)
+                            .raw("letters", letters_json(word, guess))
+                            .finish()
+                    })),
+                )
+                .finish(),
+            Event::Reveal(word) => json::Object::new()
+                .str("event", "reveal")
+                .str("word", word)
+                .finish(),
+            Event::Stats(tally) => json::Object::new()
+                .str("event", "stats")
+                .num("played", tally.played)
+                .num("won", tally.won)
+                .num("streak", tally.streak)
+                .num("max_streak", tally.max_streak)
+                .finish(),
+            Event::Share(game) => json::Object::new()
+                .str("event", "share")
+                .str("text", &game.share(Palette::Classic))
+                .finish(),
+            Event::Hard(on) => json::Object::new()
+                .str("event", "hard")
+                .bool("on", *on)
+                .finish(),
+            Event::Hint {
+                candidates,
+                position,
+                letter,
+            } => json::Object::new()
+                .str("event", "hint")
+                .num("candidates", candidates)
+                .num("position", position)
+                .str("letter", &letter.to_string())
+                .finish(),
+            Event::Undo { word, guesses } => json::Object::new()
+                .str("event", "undo")
+                .str("word", word)
+                .num("guesses", guesses)
+                .finish(),
+            Event::Analysis(reports) => json::Object::new()
+                .str("event", "analysis")
+                .raw(
+                    "guesses",
+                    json::array(reports.iter().map(|r| {
+                        json::Object::new()
+                            .str("word", &r.word)
+                            .num("before", r.before)
+                            .num("after", r.after)
+                            .num("bits", format!("{:.2}", r.bits))
+                            .num("expected_bits", format!("{:.2}", r.expected))
+                            .raw(
+                                "solver",
+                                r.solver
+                                    .map_or(String::from("null"), |w| json::string(&w.to_string())),
+                            )
+                            .num("solver_expected_bits", format!("{:.2}", r.solver_expected))
+                            .bool("lucky", r.lucky)
+                            .bool("hard_mode", r.hard_mode)
+                            .finish()
+                    })),
+                )
+                .finish(),
+            Event::Error(e) => json::Object::new()
+                .str("event", "error")
+                .str("kind", e.kind().code())
+                .str("message", &e.to_string())
+                .finish(),
+            Event::Outcome { won, word, guesses } => json::Object::new()
+                .str("event", "outcome")
+                .bool("won", *won)
+                .str("word", word)
+                .num("guesses", guesses)
+                .finish(),
+        }
+    }
+}
+
+/// Receives the events of a game as they happen, so the REPL can drive a
+/// terminal, a network connection or a test harness alike.
+pub(crate) trait Sink {
+    fn event(&mut self, event: &Event) -> io::Result<()>;
+
+    /// Called when the game is waiting for the next command.
+    fn prompt(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Called before blocking on input and once the game is over.
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Writes events to a stream in one of the output formats.
+pub(crate) struct Writer<W: Write> {
+    out: W,
+    format: Format,
+    palette: Palette,
+}
+
+impl<W: Write> Writer<W> {
+    pub(crate) fn new(out: W, format: Format) -> Self {
+        Self {
+            out,
+            format,
+            palette: Palette::default(),
+        }
+    }
+
+    /// Shows feedback in `palette` instead of the classic colors.
+    pub(crate) fn with_palette(mut self, palette: Palette) -> Self {
+        self.palette = palette;
+        self
+    }
+}
+
+impl<W: Write> Sink for Writer<W> {
+    fn event(&mut self, event: &Event) -> io::Result<()> {
+        if let Some(line) = event.render(self.format, self.palette) {
+            writeln!(self.out, "{}", line)?;
+        }
+        Ok(())
+    }
+
+    fn prompt(&mut self) -> io::Result<()> {
+        if self.format == Format::Text {
+            write!(self.out, "> ")?;
+        }
+        self.out.flush()
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.out.flush()
+    }
+}
+
+/// One line per guess: the words still fitting before and after it, the
+/// bits it gained and was expected to gain next to what the solver's choice
+/// was expected to gain, and what stood out.
+fn analysis_text(reports: &[Report]) -> String {
+    let mut lines = vec![String::from("How each guess did:")];
+    for (i, r) in reports.iter().enumerate() {
+        let mut line = format!(
+            "{}. {} {} → {} {}, {:.1} bits ({:.1} expected)",
+            i + 1,
+            r.word.to_uppercase(),
+            r.before,
+            r.after,
+            if r.after == 1 { "word" } else { "words" },
+            r.bits,
+            r.expected
+        );
+        match r.solver {
+            Some(solver) if solver.to_string() == r.word => line.push_str(", as the solver would"),
+            Some(solver) => line.push_str(&format!(
+                ", the solver's {} expected {:.1}",
+                solver.to_string().to_uppercase(),
+                r.solver_expected
+            )),
+            None => {}
+        }
+        if r.lucky {
+            line.push_str(", lucky");
+        }
+        if !r.hard_mode {
+            line.push_str(", breaks hard mode");
+        }
+        lines.push(line);
+    }
+    lines.join("\n")
+}
+
+/// Encodes the per-letter feedback of a guess as a JSON array.
+pub(crate) fn letters_json(word: &str, guess: &Guess) -> String {
+    json::array(word.chars().zip(&guess.inner).map(|(letter, result)| {
+        json::Object::new()
+            .str("letter", &letter.to_string())
+            .str("result", result.name())
+            .finish()
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::GuessChar;
+
+    #[test]
+    fn test_guess_json() {
+        let guess = Guess::from(vec![
+            GuessChar::Absent,
+            GuessChar::OutOfPlace,
+            GuessChar::Absent,
+            GuessChar::OutOfPlace,
+            GuessChar::Correct,
+        ]);
+        let event = Event::Guess {
+            word: "reads",
+            guess: &guess,
+        };
+
+        assert_eq!(
+            event.render(Format::Json, Palette::Classic).unwrap(),
+            concat!(
+                r#"{"event":"guess","word":"reads","letters":["#,
+                r#"{"letter":"r","result":"absent"},"#,
+                r#"{"letter":"e","result":"present"},"#,
+                r#"{"letter":"a","result":"absent"},"#,
+                r#"{"letter":"d","result":"present"},"#,
+                r#"{"letter":"s","result":"correct"}"#,
+                r#"],"correct":false}"#
+            )
+        );
+        assert_eq!(
+            event.render(Format::Text, Palette::Classic).unwrap(),
+            "⬛🟨⬛🟨🟩"
+        );
+    }
+
+    #[test]
+    fn test_error_json() {
+        let err = Error::from(ErrorKind::GuessTooShort(5));
+        assert_eq!(
+            Event::Error(&err)
+                .render(Format::Json, Palette::Classic)
+                .unwrap(),
+            r#"{"event":"error","kind":"guess_too_short","message":"guess too short, guesses must be 5 letters."}"#
+        );
+    }
+
+    #[test]
+    fn test_help() {
+        let text = Event::Help { length: 6 }
+            .render(Format::Text, Palette::Classic)
+            .unwrap();
+        assert!(text.contains("Can you guess the 6-letter word?"));
+        let start = Event::Start {
+            length: 7,
+            practice: true,
+            puzzle: None,
+        };
+        let text = start.render(Format::Text, Palette::Classic).unwrap();
+        assert!(text.starts_with(&help(7)));
+    }
+
+    #[test]
+    fn test_format_from_str() {
+        assert_eq!(Format::from_str("json").unwrap(), Format::Json);
+        assert_eq!(Format::from_str("text").unwrap(), Format::Text);
+        assert!(Format::from_str("yaml").is_err());
+    }
+}
@@ -0,0 +1,292 @@
+//! Just enough JSON for the machine-readable output mode and the HTTP API, so
+//! the crate doesn't need to pull in a serialization framework.
+
+use std::{fmt::Write, iter::Peekable, str::Chars};
+
+/// Encodes a string as a quoted JSON string.
+pub fn string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Encodes already-encoded JSON values as an array.
+pub fn array<I: IntoIterator<Item = String>>(items: I) -> String {
+    let items: Vec<String> = items.into_iter().collect();
+    format!("[{}]", items.join(","))
+}
+
+/// Builds a JSON object one field at a time, preserving insertion order.
+#[derive(Default)]
+pub struct Object {
+    fields: Vec<String>,
+}
+
+impl Object {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a field whose value is already encoded as JSON.
+    pub fn raw(mut self, key: &str, value: String) -> Self {
+        self.fields.push(format!("{}:{}", string(key), value));
+        self
+    }
+
+    pub fn str(self, key: &str, value: &str) -> Self {
+        self.raw(key, string(value))
+    }
+
+    pub fn num<N: std::fmt::Display>(self, key: &str, value: N) -> Self {
+        self.raw(key, value.to_string())
+    }
+
+    pub fn bool(self, key: &str, value: bool) -> Self {
+        self.raw(key, value.to_string())
+    }
+
+    /// Adds a field that is `null` when the value is missing.
+    pub fn opt_num<N: std::fmt::Display>(self, key: &str, value: Option<N>) -> Self {
+        match value {
+            Some(v) => self.num(key, v),
+            None => self.raw(key, String::from("null")),
+        }
+    }
+
+    pub fn finish(self) -> String {
+        format!("{{{}}}", self.fields.join(","))
+    }
+}
+
+/// A parsed JSON document.
+#[derive(Clone, PartialEq, Debug)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    /// Parses a complete JSON document. Returns `None` if the input is not
+    /// valid JSON.
+    pub fn parse(input: &str) -> Option<Value> {
+        let mut chars = input.chars().peekable();
+        let value = parse_value(&mut chars, 0)?;
+        skip_whitespace(&mut chars);
+        match chars.next() {
+            None => Some(value),
+            Some(_) => None,
+        }
+    }
+
+    /// Looks up a field of an object.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Object(fields) => fields.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as an unsigned integer, if it is a number without a
+    /// fractional part.
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            // u64::MAX rounds up to 2^64 as a float, which doesn't fit.
+            Value::Number(n) if *n >= 0.0 && n.fract() == 0.0 && *n < u64::MAX as f64 => {
+                Some(*n as u64)
+            }
+            _ => None,
+        }
+    }
+}
+
+fn skip_whitespace(chars: &mut Peekable<Chars>) {
+    while chars.next_if(|c| c.is_whitespace()).is_some() {}
+}
+
+fn expect(chars: &mut Peekable<Chars>, word: &str) -> Option<()> {
+    for expected in word.chars() {
+        if chars.next()? != expected {
+            return None;
+        }
+    }
+    Some(())
+}
+
+/// Arrays and objects nested deeper than this are rejected, so a hostile
+/// document can't overflow the stack.
+const MAX_DEPTH: usize = 32;
+
+fn parse_value(chars: &mut Peekable<Chars>, depth: usize) -> Option<Value> {
+    if depth > MAX_DEPTH {
+        return None;
+    }
+    skip_whitespace(chars);
+    match chars.peek()? {
+        'n' => expect(chars, "null").map(|_| Value::Null),
+        't' => expect(chars, "true").map(|_| Value::Bool(true)),
+        'f' => expect(chars, "false").map(|_| Value::Bool(false)),
+        '"' => parse_string(chars).map(Value::String),
+        '[' => {
+            chars.next();
+            let mut items = vec![];
+            skip_whitespace(chars);
+            if chars.next_if_eq(&']').is_some() {
+                return Some(Value::Array(items));
+            }
+            loop {
+                items.push(parse_value(chars, depth + 1)?);
+                skip_whitespace(chars);
+                match chars.next()? {
+                    ',' => continue,
+                    ']' => return Some(Value::Array(items)),
+                    _ => return None,
+                }
+            }
+        }
+        '{' => {
+            chars.next();
+            let mut fields = vec![];
+            skip_whitespace(chars);
+            if chars.next_if_eq(&'}').is_some() {
+                return Some(Value::Object(fields));
+            }
+            loop {
+                skip_whitespace(chars);
+                let key = parse_string(chars)?;
+                skip_whitespace(chars);
+                expect(chars, ":")?;
+                fields.push((key, parse_value(chars, depth + 1)?));
+                skip_whitespace(chars);
+                match chars.next()? {
+                    ',' => continue,
+                    '}' => return Some(Value::Object(fields)),
+                    _ => return None,
+                }
+            }
+        }
+        _ => {
+            let mut number = String::new();
+            while let Some(c) =
+                chars.next_if(|c| matches!(c, '0'..='9' | '-' | '+' | '.' | 'e' | 'E'))
+            {
+                number.push(c);
+            }
+            number.parse().ok().map(Value::Number)
+        }
+    }
+}
+
+fn parse_string(chars: &mut Peekable<Chars>) -> Option<String> {
+    expect(chars, "\"")?;
+    let mut out = String::new();
+    loop {
+        match chars.next()? {
+            '"' => return Some(out),
+            '\\' => match chars.next()? {
+                '"' => out.push('"'),
+                '\\' => out.push('\\'),
+                '/' => out.push('/'),
+                'b' => out.push('\u{8}'),
+                'f' => out.push('\u{c}'),
+                'n' => out.push('\n'),
+                'r' => out.push('\r'),
+                't' => out.push('\t'),
+                'u' => {
+                    let hex: String = (0..4).filter_map(|_| chars.next()).collect();
+                    let code = u32::from_str_radix(&hex, 16).ok()?;
+                    out.push(char::from_u32(code)?);
+                }
+                _ => return None,
+            },
+            c => out.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_string_escapes() {
+        assert_eq!(string("fudge"), "\"fudge\"");
+        assert_eq!(string("a\"b\\c\n"), "\"a\\\"b\\\\c\\n\"");
+        assert_eq!(string("\u{1}"), "\"\\u0001\"");
+    }
+
+    #[test]
+    fn test_object() {
+        let obj = Object::new()
+            .str("event", "guess")
+            .num("count", 2)
+            .bool("correct", false)
+            .opt_num::<u64>("puzzle", None)
+            .raw("letters", array(vec![string("a"), string("b")]))
+            .finish();
+        assert_eq!(
+            obj,
+            r#"{"event":"guess","count":2,"correct":false,"puzzle":null,"letters":["a","b"]}"#
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let value =
+            Value::parse(r#" {"mode": "seed", "seed": 42, "tags": [true, null], "w": "a\"b"} "#)
+                .unwrap();
+        assert_eq!(value.get("mode").and_then(Value::as_str), Some("seed"));
+        assert_eq!(value.get("seed").and_then(Value::as_u64), Some(42));
+        assert_eq!(
+            value.get("tags"),
+            Some(&Value::Array(vec![Value::Bool(true), Value::Null]))
+        );
+        assert_eq!(value.get("w").and_then(Value::as_str), Some("a\"b"));
+        assert_eq!(value.get("missing"), None);
+
+        assert_eq!(Value::parse("{}"), Some(Value::Object(vec![])));
+        assert_eq!(Value::parse("{\"a\": }"), None);
+        assert_eq!(Value::parse("[1, 2] 3"), None);
+        assert_eq!(Value::parse(""), None);
+    }
+
+    #[test]
+    fn test_parse_limits() {
+        let nested = |depth| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+        assert!(Value::parse(&nested(MAX_DEPTH)).is_some());
+        assert_eq!(Value::parse(&nested(MAX_DEPTH + 2)), None);
+        assert_eq!(Value::parse(&"[".repeat(16000)), None);
+        assert_eq!(Value::parse(&"{\"a\":".repeat(16000)), None);
+
+        assert_eq!(
+            Value::parse("18446744073709549568").unwrap().as_u64(),
+            Some(18446744073709549568)
+        );
+        assert_eq!(Value::parse("18446744073709551616").unwrap().as_u64(), None);
+        assert_eq!(Value::parse("1e30").unwrap().as_u64(), None);
+    }
+}
@@ -0,0 +1,236 @@
+//! Language profiles: the alphabet words are spelled with, how accented
+//! letters outside of it are read, and the word list to play with.
+
+use std::{
+    fmt::{self, Debug},
+    str::FromStr,
+    sync::LazyLock,
+};
+
+use crate::{dict, word::WordList, Error, ErrorKind};
+
+const ASCII: [char; 26] = [
+    'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's',
+    't', 'u', 'v', 'w', 'x', 'y', 'z',
+];
+
+const SPANISH_ALPHABET: [char; 27] = [
+    'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'ñ', 'o', 'p', 'q', 'r',
+    's', 't', 'u', 'v', 'w', 'x', 'y', 'z',
+];
+
+const GERMAN_ALPHABET: [char; 30] = [
+    'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's',
+    't', 'u', 'v', 'w', 'x', 'y', 'z', 'ä', 'ö', 'ü', 'ß',
+];
+
+pub(crate) struct Language {
+    /// ISO 639-1 code, as given to `--lang`.
+    pub(crate) code: &'static str,
+
+    pub(crate) name: &'static str,
+
+    /// The letters words are spelled with.
+    pub(crate) alphabet: &'static [char],
+
+    /// Accented letters that aren't part of the alphabet, and the letter
+    /// each one is played as.
+    folds: &'static [(char, char)],
+
+    words: LazyLock<WordList>,
+}
+
+pub(crate) static ENGLISH: Language = Language {
+    code: "en",
+    name: "English",
+    alphabet: &ASCII,
+    folds: &[],
+    words: LazyLock::new(|| WordList::parse_in(&ENGLISH, dict::EN)),
+};
+
+/// Accents are dropped except for ñ, which is a letter of its own.
+pub(crate) static SPANISH: Language = Language {
+    code: "es",
+    name: "Español",
+    alphabet: &SPANISH_ALPHABET,
+    folds: &[
+        ('á', 'a'),
+        ('é', 'e'),
+        ('í', 'i'),
+        ('ó', 'o'),
+        ('ú', 'u'),
+        ('ü', 'u'),
+    ],
+    words: LazyLock::new(|| WordList::parse_in(&SPANISH, dict::ES)),
+};
+
+pub(crate) static FRENCH: Language = Language {
+    code: "fr",
+    name: "Français",
+    alphabet: &ASCII,
+    folds: &[
+        ('à', 'a'),
+        ('â', 'a'),
+        ('ç', 'c'),
+        ('é', 'e'),
+        ('è', 'e'),
+        ('ê', 'e'),
+        ('ë', 'e'),
+        ('î', 'i'),
+        ('ï', 'i'),
+        ('ô', 'o'),
+        ('ù', 'u'),
+        ('û', 'u'),
+        ('ü', 'u'),
+        ('ÿ', 'y'),
+    ],
+    words: LazyLock::new(|| WordList::parse_in(&FRENCH, dict::FR)),
+};
+
+/// Umlauts and ß are letters of their own.
+pub(crate) static GERMAN: Language = Language {
+    code: "de",
+    name: "Deutsch",
+    alphabet: &GERMAN_ALPHABET,
+    folds: &[],
+    words: LazyLock::new(|| WordList::parse_in(&GERMAN, dict::DE)),
+};
+
+pub(crate) static PORTUGUESE: Language = Language {
+    code: "pt",
+    name: "Português",
+    alphabet: &ASCII,
+    folds: &[
+        ('á', 'a'),
+        ('à', 'a'),
+        ('â', 'a'),
+        ('ã', 'a'),
+        ('ç', 'c'),
+        ('é', 'e'),
+        ('ê', 'e'),
+        ('í', 'i'),
+        ('ó', 'o'),
+        ('ô', 'o'),
+        ('õ', 'o'),
+        ('ú', 'u'),
+        ('ü', 'u'),
+    ],
+    words: LazyLock::new(|| WordList::parse_in(&PORTUGUESE, dict::PT)),
+};
+
+pub(crate) static LANGUAGES: [&Language; 5] = [&ENGLISH, &SPANISH, &FRENCH, &GERMAN, &PORTUGUESE];
+
+impl Language {
+    /// The embedded word list, packed on first use.
+    pub(crate) fn words(&'static self) -> &'static WordList {
+        &self.words
+    }
+
+    /// Lowercases a word and folds its accented letters.
+    pub(crate) fn fold(&self, word: &str) -> String {
+        word.chars()
+            .flat_map(char::to_lowercase)
+            .map(|c| {
+                self.folds
+                    .iter()
+                    .find(|(accented, _)| *accented == c)
+                    .map_or(c, |(_, folded)| *folded)
+            })
+            .collect()
+    }
+
+    pub(crate) fn is_letter(&self, c: char) -> bool {
+        self.alphabet.contains(&c)
+    }
+}
+
+impl FromStr for &'static Language {
+    type Err = Error;
+
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        LANGUAGES
+            .iter()
+            .find(|l| l.code == code)
+            .copied()
+            .ok_or_else(|| {
+                let codes: Vec<String> = LANGUAGES
+                    .iter()
+                    .map(|l| format!("{} ({})", l.code, l.name))
+                    .collect();
+                Error::from(ErrorKind::InvalidArgument(format!(
+                    "unknown language {}, expected one of {}",
+                    code,
+                    codes.join(", ")
+                )))
+            })
+    }
+}
+
+impl PartialEq for Language {
+    fn eq(&self, other: &Self) -> bool {
+        self.code == other.code
+    }
+}
+
+impl Eq for Language {}
+
+impl Debug for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Language({})", self.code)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_fold() {
+        assert_eq!(SPANISH.fold("ÁRBOL"), "arbol");
+        assert_eq!(SPANISH.fold("Niño"), "niño");
+        assert_eq!(FRENCH.fold("élève"), "eleve");
+        assert_eq!(GERMAN.fold("Größe"), "größe");
+        assert_eq!(PORTUGUESE.fold("Avião"), "aviao");
+        assert_eq!(ENGLISH.fold("Fudge"), "fudge");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(<&Language>::from_str("de").unwrap(), &GERMAN);
+        assert!(<&Language>::from_str("xx").is_err());
+    }
+
+    #[test]
+    fn test_alphabets() {
+        // The word lists are checked against the letters in `build.rs`, which
+        // must be the alphabets here or a list could hold unplayable words.
+        for language in LANGUAGES {
+            let extra = dict::EXTRA_LETTERS
+                .iter()
+                .find(|(code, _)| *code == language.code)
+                .map_or("", |(_, letters)| letters);
+            let mut expected: Vec<char> = ('a'..='z').chain(extra.chars()).collect();
+            let mut alphabet = language.alphabet.to_vec();
+            expected.sort_unstable();
+            alphabet.sort_unstable();
+            assert_eq!(alphabet, expected, "{}", language.name);
+        }
+        assert!(dict::EXTRA_LETTERS
+            .iter()
+            .all(|(code, _)| LANGUAGES.iter().any(|l| l.code == *code)));
+    }
+
+    #[test]
+    fn test_word_lists() {
+        for language in LANGUAGES {
+            let words = language.words();
+            assert!(!words.is_empty(), "{} has no words", language.name);
+            for word in words.iter() {
+                assert_eq!(word.letters().count(), 5, "{}", word);
+                assert!(word.letters().all(|c| language.is_letter(c)), "{}", word);
+            }
+        }
+        assert!(SPANISH.words().contains("señal"));
+        assert!(GERMAN.words().contains("größe"));
+    }
+}
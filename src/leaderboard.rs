@@ -0,0 +1,418 @@
+//! Results of daily puzzles per user, for server and bot deployments where a
+//! whole team plays the same word.
+//!
+//! Results are kept in a tab-separated file with one line per user and
+//! puzzle: `user`, `puzzle`, `guesses` and `won`.
+
+use std::{
+    collections::HashSet,
+    fmt::Write as _,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+    str::FromStr,
+};
+
+use crate::{Error, ErrorKind};
+
+/// Number of puzzles covered by the weekly leaderboard.
+const WEEK: u64 = 7;
+
+/// The outcome of a daily puzzle for one user.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub(crate) struct Record {
+    pub(crate) user: String,
+    pub(crate) puzzle: u64,
+    pub(crate) guesses: usize,
+    pub(crate) won: bool,
+}
+
+impl Record {
+    fn parse(line: &str) -> Option<Self> {
+        let mut fields = line.split('\t');
+        let record = Record {
+            user: String::from(fields.next()?),
+            puzzle: fields.next()?.parse().ok()?,
+            guesses: fields.next()?.parse().ok()?,
+            won: fields.next()?.parse().ok()?,
+        };
+        match fields.next() {
+            None => Some(record),
+            Some(_) => None,
+        }
+    }
+}
+
+/// Time span a leaderboard covers.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum Period {
+    /// A single puzzle.
+    Daily,
+    /// The last seven puzzles.
+    Weekly,
+    /// Every puzzle ever played.
+    AllTime,
+}
+
+impl FromStr for Period {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "daily" => Ok(Period::Daily),
+            "weekly" => Ok(Period::Weekly),
+            "all" => Ok(Period::AllTime),
+            other => Err(Error::from(ErrorKind::InvalidArgument(format!(
+                "unknown period {}, expected daily, weekly or all",
+                other
+            )))),
+        }
+    }
+}
+
+/// A user's line on a leaderboard.
+#[derive(PartialEq, Debug)]
+pub(crate) struct Standing {
+    pub(crate) user: String,
+    pub(crate) played: usize,
+    pub(crate) won: usize,
+
+    /// Guesses summed over the puzzles that were won.
+    pub(crate) guesses: usize,
+
+    /// Consecutive puzzles won up to today, or yesterday if today's puzzle
+    /// hasn't been played yet.
+    pub(crate) streak: usize,
+    pub(crate) max_streak: usize,
+}
+
+impl Standing {
+    /// Average number of guesses over the puzzles that were won.
+    pub(crate) fn average(&self) -> Option<f64> {
+        if self.won == 0 {
+            None
+        } else {
+            Some(self.guesses as f64 / self.won as f64)
+        }
+    }
+}
+
+pub(crate) struct Leaderboard {
+    /// File the results are saved to, if any.
+    path: Option<PathBuf>,
+    records: Vec<Record>,
+
+    /// Puzzles users have started but not finished yet. These aren't saved,
+    /// only finished games are.
+    started: HashSet<(String, u64)>,
+}
+
+impl Leaderboard {
+    /// A leaderboard that is lost when the process exits.
+    pub(crate) fn in_memory() -> Self {
+        Self {
+            path: None,
+            records: vec![],
+            started: HashSet::new(),
+        }
+    }
+
+    /// Loads the results saved in `path`, which is created on the first
+    /// result if it doesn't exist yet.
+    pub(crate) fn open<P: Into<PathBuf>>(path: P) -> Result<Self, Error> {
+        let path = path.into();
+        let records = match fs::read_to_string(&path) {
+            Ok(contents) => contents
+                .lines()
+                .enumerate()
+                .filter(|(_, line)| !line.is_empty())
+                .map(|(i, line)| {
+                    Record::parse(line).ok_or_else(|| {
+                        Error::from(ErrorKind::InvalidArgument(format!(
+                            "{}:{}: malformed result",
+                            path.display(),
+                            i + 1
+                        )))
+                    })
+                })
+                .collect::<Result<_, _>>()?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => vec![],
+            Err(e) => return Err(Error::from(e)),
+        };
+        Ok(Self {
+            path: Some(path),
+            records,
+            started: HashSet::new(),
+        })
+    }
+
+    /// Whether a user has finished or started a puzzle.
+    pub(crate) fn played(&self, user: &str, puzzle: u64) -> bool {
+        self.finished(user, puzzle) || self.started.contains(&(String::from(user), puzzle))
+    }
+
+    fn finished(&self, user: &str, puzzle: u64) -> bool {
+        self.records
+            .iter()
+            .any(|r| r.user == user && r.puzzle == puzzle)
+    }
+
+    /// Reserves a user's one attempt at a puzzle when their game starts, so
+    /// that they can't start it again while it is being played.
+    pub(crate) fn start(&mut self, user: &str, puzzle: u64) -> Result<(), Error> {
+        check_user(user)?;
+        if self.played(user, puzzle) {
+            return Err(Error::from(ErrorKind::AlreadyPlayed(puzzle)));
+        }
+        self.started.insert((String::from(user), puzzle));
+        Ok(())
+    }
+
+    /// Saves a result, which ends the attempt reserved by [`Self::start`] if
+    /// any. Only the first result of a user for a puzzle counts.
+    pub(crate) fn record(&mut self, record: Record) -> Result<(), Error> {
+        check_user(&record.user)?;
+        if self.finished(&record.user, record.puzzle) {
+            return Err(Error::from(ErrorKind::AlreadyPlayed(record.puzzle)));
+        }
+
+        if let Some(path) = &self.path {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+            writeln!(
+                file,
+                "{}\t{}\t{}\t{}",
+                record.user, record.puzzle, record.guesses, record.won
+            )?;
+        }
+        self.started.remove(&(record.user.clone(), record.puzzle));
+        self.records.push(record);
+        Ok(())
+    }
+
+    /// Ranks users by puzzles won, then by average guesses.
+    pub(crate) fn standings(&self, period: Period, today: u64) -> Vec<Standing> {
+        let first = match period {
+            Period::Daily => today,
+            Period::Weekly => today.saturating_sub(WEEK - 1),
+            Period::AllTime => 0,
+        };
+
+        let mut users: Vec<&str> = self.records.iter().map(|r| r.user.as_str()).collect();
+        users.sort();
+        users.dedup();
+
+        let mut standings: Vec<Standing> = users
+            .into_iter()
+            .filter_map(|user| {
+                let mut standing = Standing {
+                    user: String::from(user),
+                    played: 0,
+                    won: 0,
+                    guesses: 0,
+                    streak: 0,
+                    max_streak: 0,
+                };
+                for record in self.records.iter().filter(|r| r.user == user) {
+                    if (first..=today).contains(&record.puzzle) {
+                        standing.played += 1;
+                        if record.won {
+                            standing.won += 1;
+                            standing.guesses += record.guesses;
+                        }
+                    }
+                }
+                let (streak, max_streak) = self.streaks(user, today);
+                standing.streak = streak;
+                standing.max_streak = max_streak;
+                (standing.played > 0).then_some(standing)
+            })
+            .collect();
+
+        standings.sort_by(|a, b| {
+            b.won.cmp(&a.won).then(
+                a.average()
+                    .unwrap_or(f64::MAX)
+                    .total_cmp(&b.average().unwrap_or(f64::MAX)),
+            )
+        });
+        standings
+    }
+
+    /// Current and longest runs of consecutive puzzles won by a user.
+    fn streaks(&self, user: &str, today: u64) -> (usize, usize) {
+        let mut won: Vec<u64> = self
+            .records
+            .iter()
+            .filter(|r| r.user == user && r.won && r.puzzle <= today)
+            .map(|r| r.puzzle)
+            .collect();
+        won.sort_unstable();
+
+        let (mut run, mut max) = (0, 0);
+        for (i, puzzle) in won.iter().enumerate() {
+            run = if i > 0 && won[i - 1] + 1 == *puzzle {
+                run + 1
+            } else {
+                1
+            };
+            max = max.max(run);
+        }
+
+        let current = match won.last() {
+            Some(&last) if last == today || last + 1 == today => run,
+            _ => 0,
+        };
+        (current, max)
+    }
+}
+
+fn check_user(user: &str) -> Result<(), Error> {
+    if user.is_empty() || user.contains(['\t', '\n', '\r']) {
+        return Err(Error::from(ErrorKind::InvalidArgument(String::from(
+            "user names can't be empty or contain tabs or line breaks",
+        ))));
+    }
+    Ok(())
+}
+
+/// Formats standings as a plain text table.
+pub(crate) fn render(standings: &[Standing]) -> String {
+    if standings.is_empty() {
+        return String::from("Nobody has played yet.");
+    }
+
+    let mut table = String::from("#  user          won/played  avg   streak  best");
+    for (rank, s) in standings.iter().enumerate() {
+        let average = s
+            .average()
+            .map(|a| format!("{:.2}", a))
+            .unwrap_or_else(|| String::from("-"));
+        let _ = write!(
+            table,
+            "\n{:<2} {:<13} {:>3}/{:<6}  {:<5} {:<7} {}",
+            rank + 1,
+            s.user,
+            s.won,
+            s.played,
+            average,
+            s.streak,
+            s.max_streak
+        );
+    }
+    table
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn record(user: &str, puzzle: u64, guesses: usize, won: bool) -> Record {
+        Record {
+            user: String::from(user),
+            puzzle,
+            guesses,
+            won,
+        }
+    }
+
+    fn leaderboard() -> Leaderboard {
+        let mut leaderboard = Leaderboard::in_memory();
+        for r in [
+            record("ana", 1, 4, true),
+            record("ana", 8, 3, true),
+            record("ana", 9, 5, true),
+            record("ana", 10, 2, true),
+            record("bob", 9, 6, false),
+            record("bob", 10, 3, true),
+            record("carl", 2, 2, true),
+        ] {
+            leaderboard.record(r).unwrap();
+        }
+        leaderboard
+    }
+
+    #[test]
+    fn test_duplicates() {
+        let mut leaderboard = leaderboard();
+        assert!(leaderboard.played("ana", 10));
+        assert!(leaderboard.record(record("ana", 10, 1, true)).is_err());
+        assert!(leaderboard.record(record("a\tb", 11, 1, true)).is_err());
+    }
+
+    #[test]
+    fn test_start() {
+        let mut leaderboard = leaderboard();
+        assert!(leaderboard.start("ana", 10).is_err());
+        leaderboard.start("ana", 11).unwrap();
+        assert!(leaderboard.played("ana", 11));
+        assert!(leaderboard.start("ana", 11).is_err());
+
+        // The result of the attempt is still saved, once.
+        leaderboard.record(record("ana", 11, 6, false)).unwrap();
+        assert!(leaderboard.record(record("ana", 11, 2, true)).is_err());
+        assert!(leaderboard.start("ana", 11).is_err());
+    }
+
+    #[test]
+    fn test_daily() {
+        let standings = leaderboard().standings(Period::Daily, 10);
+        let users: Vec<&str> = standings.iter().map(|s| s.user.as_str()).collect();
+        assert_eq!(users, vec!["ana", "bob"]);
+        assert_eq!(standings[0].average(), Some(2.0));
+    }
+
+    #[test]
+    fn test_weekly() {
+        let standings = leaderboard().standings(Period::Weekly, 10);
+        assert_eq!(standings.len(), 2);
+        assert_eq!(standings[0].user, "ana");
+        assert_eq!(standings[0].won, 3);
+        assert_eq!(standings[0].average(), Some(10.0 / 3.0));
+        assert_eq!(standings[1].played, 2);
+        assert_eq!(standings[1].won, 1);
+    }
+
+    #[test]
+    fn test_all_time_streaks() {
+        let standings = leaderboard().standings(Period::AllTime, 11);
+        let ana = standings.iter().find(|s| s.user == "ana").unwrap();
+        assert_eq!((ana.played, ana.streak, ana.max_streak), (4, 3, 3));
+        let bob = standings.iter().find(|s| s.user == "bob").unwrap();
+        assert_eq!((bob.streak, bob.max_streak), (1, 1));
+        let carl = standings.iter().find(|s| s.user == "carl").unwrap();
+        assert_eq!((carl.streak, carl.max_streak), (0, 1));
+    }
+
+    #[test]
+    fn test_file_store() {
+        let dir = std::env::temp_dir().join(format!("wordler-leaderboard-{}", std::process::id()));
+        let path = dir.join("results.tsv");
+        let _ = fs::remove_dir_all(&dir);
+
+        let mut leaderboard = Leaderboard::open(&path).unwrap();
+        leaderboard.record(record("ana", 3, 4, true)).unwrap();
+        leaderboard.record(record("bob", 3, 6, false)).unwrap();
+
+        let reopened = Leaderboard::open(&path).unwrap();
+        assert_eq!(reopened.records, leaderboard.records);
+
+        fs::write(&path, "ana\tthree\t4\ttrue\n").unwrap();
+        assert!(Leaderboard::open(&path).is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_render() {
+        let table = render(&leaderboard().standings(Period::Daily, 10));
+        assert_eq!(
+            table,
+            "#  user          won/played  avg   streak  best\n\
+             1  ana             1/1       2.00  3       3\n\
+             2  bob             1/1       3.00  1       1"
+        );
+        assert_eq!(render(&[]), "Nobody has played yet.");
+    }
+}
@@ -0,0 +1,219 @@
+//! Checks a word list for entries that don't belong in it.
+//!
+//! Lists are read like the ones in `src/dict`: one word per line, skipping
+//! blank lines and lines starting with `#`. Proper nouns are recognized by
+//! their capital letter, so in a list that was already lowercased they can
+//! only be caught by comparing it with a reference list.
+
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+    fs,
+};
+
+use crate::{lang::Language, word::WordList, Error};
+
+/// Length of the words in a list.
+const LENGTH: usize = 5;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub(crate) enum Problem {
+    ProperNoun,
+    InvalidCharacter(char),
+    WrongLength(usize),
+    /// Same word as an earlier line.
+    Duplicate(usize),
+    Plural,
+    NotInReference,
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Problem::ProperNoun => write!(f, "proper noun"),
+            Problem::InvalidCharacter(c) => write!(f, "{:?} is not a letter of the alphabet", c),
+            Problem::WrongLength(n) => write!(f, "{} letters instead of {}", n, LENGTH),
+            Problem::Duplicate(line) => write!(f, "duplicate of line {}", line),
+            Problem::Plural => write!(f, "looks like a plural"),
+            Problem::NotInReference => write!(f, "missing from the reference list"),
+        }
+    }
+}
+
+/// A problem with the entry on a line.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub(crate) struct Finding {
+    pub(crate) line: usize,
+    pub(crate) entry: String,
+    pub(crate) problem: Problem,
+}
+
+pub(crate) struct Report {
+    pub(crate) findings: Vec<Finding>,
+
+    /// The entries without any problem, folded, sorted and deduplicated.
+    pub(crate) clean: Vec<String>,
+}
+
+/// Whether a word ends in "s" the way plurals do, which leaves out words
+/// like "brass", "virus" or "basis".
+fn plural(word: &str) -> bool {
+    word.ends_with('s') && !["ss", "us", "is"].iter().any(|end| word.ends_with(end))
+}
+
+pub(crate) fn lint(
+    text: &str,
+    language: &'static Language,
+    reference: Option<&WordList>,
+) -> Report {
+    let mut findings = vec![];
+    let mut clean = vec![];
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    for (n, entry) in text.lines().enumerate() {
+        let line = n + 1;
+        let entry = entry.trim();
+        if entry.is_empty() || entry.starts_with('#') {
+            continue;
+        }
+
+        let word = language.fold(entry);
+        let mut problems = vec![];
+        if entry.chars().next().is_some_and(char::is_uppercase) {
+            problems.push(Problem::ProperNoun);
+        }
+        if let Some(c) = word.chars().find(|&c| !language.is_letter(c)) {
+            problems.push(Problem::InvalidCharacter(c));
+        }
+        let length = word.chars().count();
+        if length != LENGTH {
+            problems.push(Problem::WrongLength(length));
+        }
+        match seen.get(&word) {
+            Some(&first) => problems.push(Problem::Duplicate(first)),
+            None => {
+                seen.insert(word.clone(), line);
+            }
+        }
+        if plural(&word) {
+            problems.push(Problem::Plural);
+        }
+        if reference.is_some_and(|r| !r.contains(&word)) {
+            problems.push(Problem::NotInReference);
+        }
+
+        if problems.is_empty() {
+            clean.push(word);
+        }
+        findings.extend(problems.into_iter().map(|problem| Finding {
+            line,
+            entry: String::from(entry),
+            problem,
+        }));
+    }
+
+    clean.sort_unstable();
+    clean.dedup();
+    Report { findings, clean }
+}
+
+/// Lints the list in `path`, printing what was found and writing the
+/// cleaned list to `write` if given. Returns whether the list was clean.
+pub(crate) fn run(
+    path: &str,
+    language: &'static Language,
+    reference: Option<&str>,
+    write: Option<&str>,
+) -> Result<bool, Error> {
+    let text = fs::read_to_string(path)?;
+    let reference = match reference {
+        Some(reference) => Some(WordList::parse_in(
+            language,
+            &fs::read_to_string(reference)?,
+        )),
+        None => None,
+    };
+
+    let report = lint(&text, language, reference.as_ref());
+    for finding in &report.findings {
+        println!(
+            "{}:{}: {}: {}",
+            path, finding.line, finding.entry, finding.problem
+        );
+    }
+    println!(
+        "{} problems, {} clean words",
+        report.findings.len(),
+        report.clean.len()
+    );
+
+    if let Some(write) = write {
+        let mut out = report.clean.join("\n");
+        out.push('\n');
+        fs::write(write, out)?;
+    }
+    Ok(report.findings.is_empty())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::lang::{ENGLISH, SPANISH};
+
+    fn problems(text: &str, reference: Option<&WordList>) -> Vec<(usize, Problem)> {
+        lint(text, &ENGLISH, reference)
+            .findings
+            .into_iter()
+            .map(|f| (f.line, f.problem))
+            .collect()
+    }
+
+    #[test]
+    fn test_lint() {
+        let text = "# answers\nfudge\nAaron\n\nhéllo\nlodged\nfudge\nreads\nbrass\nvirus\n";
+        assert_eq!(
+            problems(text, None),
+            vec![
+                (3, Problem::ProperNoun),
+                (5, Problem::InvalidCharacter('é')),
+                (6, Problem::WrongLength(6)),
+                (7, Problem::Duplicate(2)),
+                (8, Problem::Plural),
+            ]
+        );
+
+        let report = lint(text, &ENGLISH, None);
+        assert_eq!(report.clean, vec!["brass", "fudge", "virus"]);
+    }
+
+    #[test]
+    fn test_reference() {
+        let reference = WordList::from("fudge\nbrass");
+        assert_eq!(
+            problems("fudge\naaron\nbrass\n", Some(&reference)),
+            vec![(2, Problem::NotInReference)]
+        );
+    }
+
+    #[test]
+    fn test_language() {
+        let report = lint("Árbol\nseñal\nniños\n", &SPANISH, None);
+        assert_eq!(report.clean, vec!["señal"]);
+        assert_eq!(report.findings[0].problem, Problem::ProperNoun);
+        assert_eq!(report.findings[1].problem, Problem::Plural);
+    }
+
+    #[test]
+    fn test_run() {
+        let dir = std::env::temp_dir().join(format!("wordler-lint-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let list = dir.join("list.txt");
+        let cleaned = dir.join("clean.txt");
+        fs::write(&list, "reads\nfudge\nAdvil\n").unwrap();
+
+        let clean = run(list.to_str().unwrap(), &ENGLISH, None, cleaned.to_str()).unwrap();
+        assert!(!clean);
+        assert_eq!(fs::read_to_string(&cleaned).unwrap(), "fudge\n");
+        assert!(run(cleaned.to_str().unwrap(), &ENGLISH, None, None).unwrap());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
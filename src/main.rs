@@ -1,41 +1,114 @@
+mod analysis;
+mod bot;
+mod config;
 mod dict;
+mod difficulty;
+mod engine;
+mod event;
+mod json;
+mod lang;
+mod leaderboard;
+mod lint;
+mod palette;
+mod race;
+mod readline;
+mod schedule;
+mod serve;
+mod telnet;
+mod transcript;
+mod tui;
+mod word;
 
-use rand::{thread_rng, Rng};
+use rand::thread_rng;
 use std::{
     collections::HashMap,
+    env,
     fmt::Debug,
     fmt::Display,
-    io::{self, Write},
+    fs,
+    io::{self, BufRead, IsTerminal, Write},
+    net::TcpListener,
+    path::PathBuf,
+    process,
     str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
 };
 
-use dict::DICT;
+use config::Config;
+use difficulty::Difficulty;
+use engine::Engine;
+use event::{Event, Format, Sink, Writer};
+use lang::{Language, ENGLISH};
+use leaderboard::{Leaderboard, Period};
+use palette::Palette;
+use readline::LineEditor;
+use schedule::{Seen, SplitMix64};
+use transcript::{Recorder, Transcript};
+use word::{Word, WordList};
 
-const LETTERS: [char; 26] = [
-    'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's',
-    't', 'u', 'v', 'w', 'x', 'y', 'z',
-];
+/// Start of the first daily puzzle (2021-06-19 UTC), in seconds since the Unix
+/// epoch. Puzzle numbers count the days elapsed since then.
+const PUZZLE_EPOCH: u64 = 1_624_060_800;
 
-#[derive(PartialEq, Eq, Debug)]
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// A file in wordler's state directory, `$XDG_STATE_HOME/wordler` or
+/// `~/.local/state/wordler` if it isn't set.
+fn state_path(file: &str) -> Option<PathBuf> {
+    let state = match env::var_os("XDG_STATE_HOME") {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => PathBuf::from(env::var_os("HOME")?).join(".local/state"),
+    };
+    Some(state.join("wordler").join(file))
+}
+
+/// Returns the number of today's daily puzzle.
+fn today_puzzle() -> u64 {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(PUZZLE_EPOCH);
+    now.saturating_sub(PUZZLE_EPOCH) / SECONDS_PER_DAY
+}
+
+/// Picks a word deterministically: the same seed always maps to the same word
+/// for a given dictionary, in every build.
+fn seeded_word(dict: &WordList, seed: u64) -> Option<Word> {
+    if dict.is_empty() {
+        return None;
+    }
+    dict.get(SplitMix64::new(seed).below(dict.len()))
+}
+
+/// Feedback for a single letter. Variants are ordered from the least to the
+/// most informative.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
 enum GuessChar {
     Absent,
     OutOfPlace,
     Correct,
 }
 
-impl Display for GuessChar {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl GuessChar {
+    /// Stable name used in machine-readable output.
+    fn name(&self) -> &'static str {
         match self {
-            GuessChar::Absent => write!(f, "⬛"),
-            GuessChar::OutOfPlace => write!(f, "🟨"),
-            GuessChar::Correct => write!(f, "🟩"),
+            GuessChar::Absent => "absent",
+            GuessChar::OutOfPlace => "present",
+            GuessChar::Correct => "correct",
         }
     }
 }
 
+impl Display for GuessChar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Palette::Classic.square(*self))
+    }
+}
+
 /// Guess represents a complete guessed word, made up of a list of guessed
 /// charaters.
-#[derive(PartialEq, Eq, Debug)]
+#[derive(Clone, PartialEq, Eq, Debug)]
 struct Guess {
     inner: Vec<GuessChar>,
 }
@@ -56,39 +129,183 @@ impl From<Vec<GuessChar>> for Guess {
 }
 
 impl Guess {
-    fn correct(self) -> bool {
+    fn correct(&self) -> bool {
         self.inner.iter().all(|r| r == &GuessChar::Correct)
     }
+
+    /// Compact form of the feedback used by the line protocols: one digit per
+    /// letter, `0` for absent, `1` for out of place and `2` for correct.
+    fn pattern(&self) -> String {
+        self.inner
+            .iter()
+            .map(|r| match r {
+                GuessChar::Absent => '0',
+                GuessChar::OutOfPlace => '1',
+                GuessChar::Correct => '2',
+            })
+            .collect()
+    }
+
+    /// Parses the compact form produced by `pattern`.
+    fn from_pattern(pattern: &str) -> Option<Self> {
+        pattern
+            .chars()
+            .map(|c| match c {
+                '0' => Some(GuessChar::Absent),
+                '1' => Some(GuessChar::OutOfPlace),
+                '2' => Some(GuessChar::Correct),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()
+            .map(Guess::from)
+    }
 }
 
-struct Error {
+/// Everything that can go wrong while playing or setting up a game.
+#[derive(Debug)]
+pub struct Error {
     kind: ErrorKind,
 }
 
-enum ErrorKind {
-    GuessTooShort,
-    GuessTooLong,
+/// The reason an operation failed. Each kind has a stable code, see
+/// [`ErrorKind::code`], and new kinds may be added over time.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// Guesses must have as many letters as the word.
+    GuessTooShort(usize),
+    GuessTooLong(usize),
+    /// A guess with a character that isn't a letter of the game's alphabet,
+    /// at a 1-based position.
+    InvalidCharacter {
+        character: char,
+        position: usize,
+    },
+    /// A guess that ignores an earlier hint in hard mode.
+    HardMode(String),
     InvalidCommand,
+    /// A REPL command that doesn't exist, and the closest one that does.
+    UnknownCommand(String, Option<&'static str>),
+    /// A REPL command given arguments it doesn't take, with its usage.
+    CommandUsage(&'static str),
+    NoWordsOfLength(usize),
+    /// A REPL command that would spoil or skip the daily puzzle.
+    NotInDaily(&'static str),
+    /// `/undo` in a game whose result counts, that is anything but a
+    /// practice game.
+    UndoUnavailable,
+    NothingToUndo,
+    NotInWordList(String),
+    FuturePuzzle(u64),
+    InvalidArgument(String),
+    NoGame,
+    UnknownGame(String),
+    GameOver,
+    RaceStarted,
+    RaceNotStarted,
+    NameTaken(String),
+    InvalidTranscript(String),
+    InvalidConfig(String),
+    AlreadyPlayed(u64),
     IoError(io::Error),
 }
 
 impl std::error::Error for Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self.kind {
-            ErrorKind::GuessTooShort => None,
-            ErrorKind::GuessTooLong => None,
+            ErrorKind::GuessTooShort(_) => None,
+            ErrorKind::GuessTooLong(_) => None,
+            ErrorKind::InvalidCharacter { .. } => None,
+            ErrorKind::HardMode(_) => None,
             ErrorKind::InvalidCommand => None,
-            ErrorKind::IoError(ref e) => e.source(),
+            ErrorKind::UnknownCommand(..) => None,
+            ErrorKind::CommandUsage(_) => None,
+            ErrorKind::NoWordsOfLength(_) => None,
+            ErrorKind::NotInDaily(_) => None,
+            ErrorKind::UndoUnavailable => None,
+            ErrorKind::NothingToUndo => None,
+            ErrorKind::NotInWordList(_) => None,
+            ErrorKind::FuturePuzzle(_) => None,
+            ErrorKind::InvalidArgument(_) => None,
+            ErrorKind::NoGame => None,
+            ErrorKind::UnknownGame(_) => None,
+            ErrorKind::GameOver => None,
+            ErrorKind::RaceStarted => None,
+            ErrorKind::RaceNotStarted => None,
+            ErrorKind::NameTaken(_) => None,
+            ErrorKind::InvalidTranscript(_) => None,
+            ErrorKind::InvalidConfig(_) => None,
+            ErrorKind::AlreadyPlayed(_) => None,
+            ErrorKind::IoError(ref e) => Some(e),
+        }
+    }
+}
+
+impl ErrorKind {
+    /// Stable identifier used in machine-readable output. Codes are never
+    /// changed or reused once released.
+    pub fn code(&self) -> &'static str {
+        match self {
+            ErrorKind::GuessTooShort(_) => "guess_too_short",
+            ErrorKind::GuessTooLong(_) => "guess_too_long",
+            ErrorKind::InvalidCharacter { .. } => "invalid_character",
+            ErrorKind::HardMode(_) => "hard_mode",
+            ErrorKind::InvalidCommand => "invalid_command",
+            ErrorKind::UnknownCommand(..) => "unknown_command",
+            ErrorKind::CommandUsage(_) => "command_usage",
+            ErrorKind::NoWordsOfLength(_) => "no_words_of_length",
+            ErrorKind::NotInDaily(_) => "not_in_daily",
+            ErrorKind::UndoUnavailable => "undo_unavailable",
+            ErrorKind::NothingToUndo => "nothing_to_undo",
+            ErrorKind::NotInWordList(_) => "not_in_word_list",
+            ErrorKind::FuturePuzzle(_) => "future_puzzle",
+            ErrorKind::InvalidArgument(_) => "invalid_argument",
+            ErrorKind::NoGame => "no_game",
+            ErrorKind::UnknownGame(_) => "unknown_game",
+            ErrorKind::GameOver => "game_over",
+            ErrorKind::RaceStarted => "race_started",
+            ErrorKind::RaceNotStarted => "race_not_started",
+            ErrorKind::NameTaken(_) => "name_taken",
+            ErrorKind::InvalidTranscript(_) => "invalid_transcript",
+            ErrorKind::InvalidConfig(_) => "invalid_config",
+            ErrorKind::AlreadyPlayed(_) => "already_played",
+            ErrorKind::IoError(_) => "io_error",
         }
     }
 }
 
 impl Error {
-    fn retryable(self) -> bool {
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+
+    /// Whether the player can carry on after this error, such as after a
+    /// guess that isn't in the word list.
+    pub fn retryable(&self) -> bool {
         match self.kind {
-            ErrorKind::GuessTooShort => true,
-            ErrorKind::GuessTooLong => true,
+            ErrorKind::GuessTooShort(_) => true,
+            ErrorKind::GuessTooLong(_) => true,
+            ErrorKind::InvalidCharacter { .. } => true,
+            ErrorKind::HardMode(_) => true,
             ErrorKind::InvalidCommand => true,
+            ErrorKind::UnknownCommand(..) => true,
+            ErrorKind::CommandUsage(_) => true,
+            ErrorKind::NoWordsOfLength(_) => true,
+            ErrorKind::NotInDaily(_) => true,
+            ErrorKind::UndoUnavailable => true,
+            ErrorKind::NothingToUndo => true,
+            ErrorKind::NotInWordList(_) => true,
+            ErrorKind::FuturePuzzle(_) => false,
+            ErrorKind::InvalidArgument(_) => false,
+            ErrorKind::NoGame => true,
+            ErrorKind::UnknownGame(_) => true,
+            ErrorKind::GameOver => true,
+            ErrorKind::RaceStarted => true,
+            ErrorKind::RaceNotStarted => true,
+            ErrorKind::NameTaken(_) => true,
+            ErrorKind::InvalidTranscript(_) => false,
+            ErrorKind::InvalidConfig(_) => false,
+            ErrorKind::AlreadyPlayed(_) => true,
             ErrorKind::IoError(_) => false,
         }
     }
@@ -111,20 +328,113 @@ impl From<io::Error> for Error {
 impl Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match &self.kind {
-            ErrorKind::GuessTooShort => write!(f, "guess too short, guesses must be 5 letters."),
-            ErrorKind::GuessTooLong => write!(f, "guess too long, guesses must be 5 letters."),
+            ErrorKind::GuessTooShort(n) => {
+                write!(f, "guess too short, guesses must be {} letters.", n)
+            }
+            ErrorKind::GuessTooLong(n) => {
+                write!(f, "guess too long, guesses must be {} letters.", n)
+            }
+            ErrorKind::InvalidCharacter {
+                character,
+                position,
+            } => write!(
+                f,
+                "{:?} at position {} is not a letter.",
+                character, position
+            ),
+            ErrorKind::HardMode(rule) => write!(f, "hard mode: {}.", rule),
             ErrorKind::InvalidCommand => write!(
                 f,
                 "unknown command. use /help to list all available commands"
             ),
+            ErrorKind::UnknownCommand(command, Some(suggestion)) => {
+                write!(
+                    f,
+                    "unknown command {}, did you mean {}?",
+                    command, suggestion
+                )
+            }
+            ErrorKind::UnknownCommand(command, None) => write!(
+                f,
+                "unknown command {}. use /help to list all available commands",
+                command
+            ),
+            ErrorKind::CommandUsage(usage) => write!(f, "usage: {}", usage),
+            ErrorKind::NoWordsOfLength(n) => {
+                write!(f, "there are no {}-letter words in the word list.", n)
+            }
+            ErrorKind::NotInDaily(command) => {
+                write!(f, "{} isn't available in the daily puzzle.", command)
+            }
+            ErrorKind::UndoUnavailable => {
+                write!(f, "guesses can only be taken back in practice games.")
+            }
+            ErrorKind::NothingToUndo => write!(f, "there is no guess to take back."),
+            ErrorKind::NotInWordList(word) => write!(f, "{} is not in the word list.", word),
+            ErrorKind::FuturePuzzle(n) => write!(
+                f,
+                "puzzle #{} hasn't been published yet, today's puzzle is #{}.",
+                n,
+                today_puzzle()
+            ),
+            ErrorKind::InvalidArgument(msg) => write!(f, "invalid argument: {}", msg),
+            ErrorKind::NoGame => write!(f, "no game in progress, start one with newgame."),
+            ErrorKind::UnknownGame(id) => write!(f, "no game with id {}.", id),
+            ErrorKind::GameOver => write!(f, "the game is over, start a new one."),
+            ErrorKind::RaceStarted => write!(f, "the race has already started."),
+            ErrorKind::RaceNotStarted => write!(f, "the race hasn't started yet."),
+            ErrorKind::NameTaken(name) => write!(f, "{} is already taken.", name),
+            ErrorKind::InvalidTranscript(msg) => write!(f, "invalid transcript: {}", msg),
+            ErrorKind::InvalidConfig(msg) => write!(f, "invalid config: {}", msg),
+            ErrorKind::AlreadyPlayed(puzzle) => {
+                write!(f, "puzzle #{} has already been played.", puzzle)
+            }
             ErrorKind::IoError(err) => write!(f, "io error: {}", err),
         }
     }
 }
 
-impl Debug for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self)
+/// How the secret word of a game was chosen.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Mode {
+    /// Picked at random.
+    Random,
+    /// Picked from a seed, so the game can be played again.
+    Seed,
+    /// Today's daily puzzle.
+    Daily,
+    /// A past daily puzzle, played for practice.
+    Puzzle,
+    /// A word chosen by the player, played for practice.
+    Word,
+    /// The next word the player hasn't been given yet.
+    Next,
+}
+
+impl Mode {
+    /// Stable name used in transcripts and machine-readable output.
+    fn name(&self) -> &'static str {
+        match self {
+            Mode::Random => "random",
+            Mode::Seed => "seed",
+            Mode::Daily => "daily",
+            Mode::Puzzle => "puzzle",
+            Mode::Word => "word",
+            Mode::Next => "next",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        [
+            Mode::Random,
+            Mode::Seed,
+            Mode::Daily,
+            Mode::Puzzle,
+            Mode::Word,
+            Mode::Next,
+        ]
+        .into_iter()
+        .find(|mode| mode.name() == name)
     }
 }
 
@@ -138,46 +448,282 @@ struct Game {
     /// The list of all letters, mapping to a boolean showing whether or not it
     /// has been used yet. Initialized to `false`.
     used_letters: HashMap<char, bool>,
+
+    /// How the word was chosen.
+    mode: Mode,
+
+    /// The seed the word was picked with, if any.
+    seed: Option<u64>,
+
+    /// The daily puzzle number this game plays or replays, if any.
+    puzzle: Option<u64>,
+
+    /// The valid guesses made so far, along with their feedback.
+    history: Vec<(String, Guess)>,
+
+    /// The language of the word, which sets the letters and accents guesses
+    /// are read with.
+    language: &'static Language,
+
+    /// Guesses allowed before the game is lost, unlimited if `None`.
+    max_guesses: Option<usize>,
+
+    /// Whether guesses must use every hint revealed so far.
+    hard: bool,
+
+    /// Games finished so far in this session, carried over to new games.
+    tally: Tally,
+}
+
+/// Games finished in a REPL session.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+struct Tally {
+    played: usize,
+    won: usize,
+    streak: usize,
+    max_streak: usize,
+}
+
+impl Tally {
+    fn record(&mut self, won: bool) {
+        self.played += 1;
+        if won {
+            self.won += 1;
+            self.streak += 1;
+            self.max_streak = self.max_streak.max(self.streak);
+        } else {
+            self.streak = 0;
+        }
+    }
 }
 
 impl Game {
-    fn help(&self) {
-        println!(
-            "Welcome to Wordler!
-A Wordle REPL thingy. Can you guess the five letter word?
-
-COMMANDS:
-\t/help\tPrints this help text.
-\t/letters\tShows the letters that have not been tried yet.
-\t/exit\tExits the game."
-        );
+    /// An English game against `word`, for tests that don't care about
+    /// the language.
+    #[cfg(test)]
+    fn new(word: String) -> Self {
+        Self::in_language(&ENGLISH, word)
+    }
+
+    fn in_language(language: &'static Language, word: String) -> Self {
+        let mut letters = HashMap::new();
+        for &l in language.alphabet {
+            letters.insert(l, false);
+        }
+
+        Self {
+            word,
+            keep_going: true,
+            used_letters: letters,
+            mode: Mode::Random,
+            seed: None,
+            puzzle: None,
+            history: vec![],
+            language,
+            max_guesses: None,
+            hard: false,
+            tally: Tally::default(),
+        }
+    }
+
+    /// Starts a practice game against a specific word, which must be part of
+    /// the dictionary.
+    fn with_word(dict: &WordList, word: &str) -> Result<Self, Error> {
+        let word = dict.language().fold(word);
+        if !dict.contains(&word) {
+            return Err(Error::from(ErrorKind::NotInWordList(word)));
+        }
+
+        let mut game = Self::in_language(dict.language(), word);
+        game.mode = Mode::Word;
+        Ok(game)
+    }
+
+    /// Starts a game whose word is picked deterministically from the seed.
+    fn with_seed(dict: &WordList, seed: u64) -> Self {
+        let word = seeded_word(dict, seed).map_or_else(|| String::from("fudge"), |w| w.to_string());
+        let mut game = Self::in_language(dict.language(), word);
+        game.mode = Mode::Seed;
+        game.seed = Some(seed);
+        game
+    }
+
+    /// Starts a daily puzzle, whose word comes from the daily schedule so no
+    /// answer repeats until every word has had its day.
+    fn with_schedule(dict: &WordList, puzzle: u64) -> Result<Self, Error> {
+        let word = schedule::daily(dict, puzzle).ok_or_else(empty_list)?;
+        let mut game = Self::in_language(dict.language(), word.to_string());
+        game.mode = Mode::Daily;
+        game.puzzle = Some(puzzle);
+        Ok(game)
+    }
+
+    /// Starts today's daily puzzle.
+    fn daily(dict: &WordList) -> Result<Self, Error> {
+        Self::with_schedule(dict, today_puzzle())
+    }
+
+    /// Starts a game with the next word of the schedule the player hasn't
+    /// seen yet, starting a new round once they have seen them all, and
+    /// remembers it was given.
+    fn next(dict: &WordList, seen: &mut Seen) -> Result<Self, Error> {
+        if dict.is_empty() {
+            return Err(empty_list());
+        }
+        if schedule::next(dict, seen).is_none() {
+            seen.new_round()?;
+        }
+        let word = schedule::next(dict, seen)
+            .ok_or_else(empty_list)?
+            .to_string();
+        seen.insert(&word)?;
+        let mut game = Self::in_language(dict.language(), word);
+        game.mode = Mode::Next;
+        Ok(game)
     }
 
-    fn letters(&self) {
-        let mut unused: Vec<String> = self
+    /// Starts a practice game replaying a past daily puzzle.
+    fn with_puzzle(dict: &WordList, puzzle: u64) -> Result<Self, Error> {
+        if puzzle > today_puzzle() {
+            return Err(Error::from(ErrorKind::FuturePuzzle(puzzle)));
+        }
+
+        let mut game = Self::with_schedule(dict, puzzle)?;
+        game.mode = Mode::Puzzle;
+        Ok(game)
+    }
+
+    /// Practice games are played against a chosen word or a past puzzle and
+    /// don't count toward stats.
+    fn practice(&self) -> bool {
+        matches!(self.mode, Mode::Word | Mode::Puzzle)
+    }
+
+    /// Spoiler-free summary of the game, with one row of colored squares per
+    /// guess.
+    fn share(&self, palette: Palette) -> String {
+        let title = match (self.mode, self.puzzle) {
+            (Mode::Daily, Some(puzzle)) => format!("Wordler #{}", puzzle),
+            (Mode::Puzzle, Some(puzzle)) => format!("Wordler #{} (practice)", puzzle),
+            _ => String::from("Wordler"),
+        };
+        let mut share = if self.won() {
+            format!("{} {}", title, self.history.len())
+        } else {
+            format!("{} X", title)
+        };
+        for (_, guess) in &self.history {
+            share.push('\n');
+            share.push_str(&palette.squares(guess));
+        }
+        share
+    }
+
+    fn won(&self) -> bool {
+        self.history.last().is_some_and(|(_, g)| g.correct())
+    }
+
+    fn out_of_guesses(&self) -> bool {
+        self.max_guesses
+            .is_some_and(|max| self.history.len() >= max)
+    }
+
+    fn help(&self, sink: &mut dyn Sink) -> io::Result<()> {
+        sink.event(&Event::Help {
+            length: self.word.chars().count(),
+        })
+    }
+
+    fn letters(&self, sink: &mut dyn Sink) -> io::Result<()> {
+        let mut unused: Vec<char> = self
             .used_letters
             .iter()
             .filter(|(_, &used)| !used)
-            .map(|(letter, _)| letter.to_string())
+            .map(|(&letter, _)| letter)
             .collect();
 
         unused.sort();
 
-        println!("{}", unused.join(" "));
+        sink.event(&Event::Letters(&unused))
+    }
+
+    /// Ends the game and reports the outcome, followed by how each guess
+    /// fared if there were any.
+    fn finish(&mut self, dict: &WordList, sink: &mut dyn Sink, won: bool) -> io::Result<()> {
+        self.keep_going = false;
+        self.tally.record(won);
+        sink.event(&Event::Outcome {
+            won,
+            word: &self.word,
+            guesses: self.history.len(),
+        })?;
+        if self.history.is_empty() {
+            return Ok(());
+        }
+        sink.event(&Event::Analysis(&analysis::analyze(dict, self)))
+    }
+
+    /// The most informative feedback received so far for each letter that has
+    /// been guessed.
+    fn keyboard(&self) -> HashMap<char, GuessChar> {
+        Self::keyboard_of(&self.history)
+    }
+
+    fn keyboard_of(history: &[(String, Guess)]) -> HashMap<char, GuessChar> {
+        let mut keyboard = HashMap::new();
+        for (word, guess) in history {
+            for (letter, result) in word.chars().zip(&guess.inner) {
+                let known = keyboard.entry(letter).or_insert(*result);
+                *known = (*known).max(*result);
+            }
+        }
+        keyboard
+    }
+
+    /// The first hint from earlier guesses that `guess` ignores, which hard
+    /// mode doesn't allow: correct letters must stay in place and letters
+    /// found elsewhere must be used again.
+    fn hard_mode_violation(history: &[(String, Guess)], guess: &str) -> Option<String> {
+        let letters: Vec<char> = guess.chars().collect();
+        for (word, feedback) in history {
+            for (i, (letter, result)) in word.chars().zip(&feedback.inner).enumerate() {
+                match result {
+                    GuessChar::Correct if letters.get(i) != Some(&letter) => {
+                        return Some(format!(
+                            "letter {} must be {}",
+                            i + 1,
+                            letter.to_uppercase()
+                        ))
+                    }
+                    GuessChar::OutOfPlace if !letters.contains(&letter) => {
+                        return Some(format!("guess must contain {}", letter.to_uppercase()))
+                    }
+                    _ => {}
+                }
+            }
+        }
+        None
     }
 
     /// Evaluate a guess against the secret word.
     fn guess(&mut self, guess: String) -> Result<Guess, Error> {
-        match guess.len() {
-            l if l < 5 => return Err(Error::from(ErrorKind::GuessTooShort)),
-            l if l > 5 => return Err(Error::from(ErrorKind::GuessTooLong)),
+        self.check_letters(&guess)?;
+        let guess = self.language.fold(&guess);
+        let length = self.word.chars().count();
+        match guess.chars().count() {
+            l if l < length => return Err(Error::from(ErrorKind::GuessTooShort(length))),
+            l if l > length => return Err(Error::from(ErrorKind::GuessTooLong(length))),
             _ => {}
         }
-
-        let guess = guess.to_ascii_lowercase();
+        if self.hard {
+            if let Some(rule) = Self::hard_mode_violation(&self.history, &guess) {
+                return Err(Error::from(ErrorKind::HardMode(rule)));
+            }
+        }
 
         // Compare words
         let mut res = vec![];
+        let answer = Word::parse(&self.word).unwrap_or_default();
         let mut word_chars = self.word.chars();
         for c in guess.chars() {
             let maybe_next = word_chars.next();
@@ -185,11 +731,11 @@ COMMANDS:
                 // The None case should never happen since the length is checked
                 // earlier, this makes the compiler happy at the cost of a
                 // little added verbosity
-                None => return Err(Error::from(ErrorKind::GuessTooLong)),
+                None => return Err(Error::from(ErrorKind::GuessTooLong(length))),
                 Some(same_pos) => {
                     if c == same_pos {
                         res.push(GuessChar::Correct);
-                    } else if self.word.contains(c) {
+                    } else if answer.contains(c) {
                         res.push(GuessChar::OutOfPlace);
                     } else {
                         res.push(GuessChar::Absent);
@@ -198,51 +744,192 @@ COMMANDS:
             }
 
             // Adjust the used_letters map
-            self.used_letters
-                .entry(c)
-                .and_modify(|e| *e = true)
-                .or_insert(true);
+            if let Some(used) = self.used_letters.get_mut(&c) {
+                *used = true;
+            }
+        }
+
+        let res = Guess::from(res);
+        self.history.push((guess, res.clone()));
+        Ok(res)
+    }
+
+    /// Rejects a guess with a character that isn't a letter of the game's
+    /// alphabet, once accents are folded, before it's checked any further.
+    fn check_letters(&self, guess: &str) -> Result<(), Error> {
+        let invalid = guess.chars().enumerate().find(|(_, c)| {
+            !self
+                .language
+                .fold(&c.to_string())
+                .chars()
+                .all(|c| self.language.is_letter(c))
+        });
+        match invalid {
+            Some((i, character)) => Err(Error::from(ErrorKind::InvalidCharacter {
+                character,
+                position: i + 1,
+            })),
+            None => Ok(()),
+        }
+    }
+
+    /// Takes back the last guess. Only practice games allow it, since every
+    /// other game counts toward stats.
+    fn undo(&mut self) -> Result<String, Error> {
+        if !self.practice() {
+            return Err(Error::from(ErrorKind::UndoUnavailable));
+        }
+        let (word, _) = self
+            .history
+            .pop()
+            .ok_or(Error::from(ErrorKind::NothingToUndo))?;
+
+        // A letter of the undone guess may have been tried before too, so
+        // the keyboard is rebuilt from the guesses that are left.
+        for used in self.used_letters.values_mut() {
+            *used = false;
         }
+        for c in self.history.iter().flat_map(|(word, _)| word.chars()) {
+            if let Some(used) = self.used_letters.get_mut(&c) {
+                *used = true;
+            }
+        }
+        Ok(word)
+    }
+
+    /// Words of `dict` that fit the feedback of every guess so far.
+    fn candidates(&self, dict: &WordList) -> Vec<Word> {
+        analysis::candidates(dict.iter(), self.word.chars().count(), &self.history)
+    }
 
-        Ok(Guess::from(res))
+    /// Reveals a letter of the word the player hasn't placed yet.
+    fn hint(&self, dict: &WordList, sink: &mut dyn Sink) -> io::Result<()> {
+        if self.mode == Mode::Daily {
+            return sink.event(&Event::Error(&Error::from(ErrorKind::NotInDaily("/hint"))));
+        }
+        let found = |i: usize| {
+            self.history
+                .iter()
+                .any(|(_, guess)| guess.inner.get(i) == Some(&GuessChar::Correct))
+        };
+        let Some((i, letter)) = self.word.chars().enumerate().find(|&(i, _)| !found(i)) else {
+            return sink.event(&Event::Error(&Error::from(ErrorKind::GameOver)));
+        };
+        sink.event(&Event::Hint {
+            candidates: self.candidates(dict).len(),
+            position: i + 1,
+            letter,
+        })
+    }
+
+    /// Gives up on this game and starts another one with a random word of
+    /// `length` letters, keeping the settings and the session's tally.
+    fn restart(
+        &mut self,
+        dict: &WordList,
+        sink: &mut dyn Sink,
+        length: Option<usize>,
+    ) -> io::Result<()> {
+        // Giving up the daily puzzle for another game would reveal its word
+        // without the result being recorded.
+        if self.mode == Mode::Daily {
+            return sink.event(&Event::Error(&Error::from(ErrorKind::NotInDaily("/new"))));
+        }
+        let length = length.unwrap_or_else(|| self.word.chars().count());
+        let words = dict.filter(|w| w.letters().count() == length);
+        if words.is_empty() {
+            let e = Error::from(ErrorKind::NoWordsOfLength(length));
+            return sink.event(&Event::Error(&e));
+        }
+
+        sink.event(&Event::Reveal(&self.word))?;
+        self.finish(dict, sink, false)?;
+        let mut game = Game::from(&words);
+        game.max_guesses = self.max_guesses;
+        game.hard = self.hard;
+        game.tally = self.tally;
+        *self = game;
+        self.start(sink)
+    }
+
+    fn start(&self, sink: &mut dyn Sink) -> io::Result<()> {
+        sink.event(&Event::Start {
+            length: self.word.chars().count(),
+            practice: self.practice(),
+            puzzle: self.puzzle,
+        })
     }
 
     /// Evaluate a Command in the context of the current game instance. Returns
     /// a boolean set to true if the program should keep going.
-    fn eval(&mut self, cmd: Command) {
+    fn eval(&mut self, dict: &WordList, sink: &mut dyn Sink, cmd: Command) -> io::Result<()> {
         match cmd {
-            Command::Guess(guess) => match self.guess(guess) {
+            Command::Guess(guess) => match self.guess(guess.clone()) {
                 Ok(g) => {
-                    println!("{}", g);
+                    sink.event(&Event::Guess {
+                        word: &self.language.fold(&guess),
+                        guess: &g,
+                    })?;
                     if g.correct() {
-                        println!("Congrats! 🎉");
-                        self.keep_going = false;
+                        self.finish(dict, sink, true)?;
+                    } else if self.out_of_guesses() {
+                        sink.event(&Event::Reveal(&self.word))?;
+                        self.finish(dict, sink, false)?;
                     }
+                    Ok(())
                 }
-                Err(e) => println!("{}", e),
+                Err(e) => sink.event(&Event::Error(&e)),
             },
-            Command::Help => self.help(),
-            Command::Letters => self.letters(),
-            Command::Exit => self.keep_going = false,
+            Command::Help => self.help(sink),
+            Command::Letters => self.letters(sink),
+            Command::Board => sink.event(&Event::Board(&self.history)),
+            Command::New(length) => self.restart(dict, sink, length),
+            Command::GiveUp => {
+                sink.event(&Event::Reveal(&self.word))?;
+                self.finish(dict, sink, false)
+            }
+            Command::Stats => sink.event(&Event::Stats(&self.tally)),
+            Command::Share => sink.event(&Event::Share(self)),
+            Command::Hard(on) => {
+                self.hard = on.unwrap_or(!self.hard);
+                sink.event(&Event::Hard(self.hard))
+            }
+            Command::Hint => self.hint(dict, sink),
+            Command::Undo => match self.undo() {
+                Ok(word) => sink.event(&Event::Undo {
+                    word: &word,
+                    guesses: self.history.len(),
+                }),
+                Err(e) => sink.event(&Event::Error(&e)),
+            },
+            Command::Exit => self.finish(dict, sink, false),
         }
     }
 
-    /// Starts a repl for the current game instance. This assumes the process
-    /// is a TTY.
-    fn repl(mut self) -> Result<(), Error> {
-        self.help();
+    /// Starts a repl for the current game instance, reading commands from
+    /// `input` and reporting everything that happens to the `sink`.
+    fn repl<R: BufRead>(
+        &mut self,
+        dict: &WordList,
+        mut input: R,
+        sink: &mut dyn Sink,
+    ) -> Result<(), Error> {
+        self.start(sink)?;
 
-        let mut input = String::new();
+        let mut line = String::new();
         while self.keep_going {
-            print!("> ");
-            io::stdout().flush()?;
+            sink.prompt()?;
 
-            io::stdin().read_line(&mut input)?;
-            input = input.trim().into();
-            match Command::from_str(input.as_str()) {
-                Ok(cmd) => self.eval(cmd),
+            // End of input, e.g. when stdin is piped from another program.
+            if input.read_line(&mut line)? == 0 {
+                self.eval(dict, sink, Command::Exit)?;
+                break;
+            }
+            line = line.trim().into();
+            match Command::from_str(line.as_str()) {
+                Ok(cmd) => self.eval(dict, sink, cmd)?,
                 Err(e) => {
-                    println!("{}", e);
+                    sink.event(&Event::Error(&e))?;
                     if !e.retryable() {
                         break;
                     }
@@ -250,61 +937,918 @@ COMMANDS:
             }
 
             // reset input on each guess
-            input = String::new();
+            line = String::new();
         }
 
+        sink.flush()?;
         Ok(())
     }
 }
 
-/// Forms a new game by splitting the provided dictionary into individual words
-/// and picking one at random.
-impl From<String> for Game {
-    fn from(dict: String) -> Self {
-        let words = dict.lines();
-        let word = words
-            .clone()
-            .nth(thread_rng().gen_range(0..words.count()))
-            .unwrap_or("fudge")
-            .to_string();
+/// The error for a word list with no word to play.
+fn empty_list() -> Error {
+    Error::from(ErrorKind::InvalidArgument(String::from(
+        "the word list is empty",
+    )))
+}
 
-        let mut letters = HashMap::new();
-        for l in LETTERS {
-            letters.insert(l, false);
-        }
+/// Forms a new game by picking a word from the dictionary at random.
+impl From<&WordList> for Game {
+    fn from(dict: &WordList) -> Self {
+        let word = dict
+            .choose(&mut thread_rng())
+            .map_or_else(|| String::from("fudge"), |w| w.to_string());
 
-        Self {
-            word,
-            keep_going: true,
-            used_letters: letters,
-        }
+        Self::in_language(dict.language(), word)
     }
 }
 
+#[derive(PartialEq, Eq, Debug)]
 enum Command {
     Guess(String),
     Help,
     Letters,
+    /// Show every guess so far.
+    Board,
+    /// Give up and start a new game, with words of this length if given.
+    New(Option<usize>),
+    GiveUp,
+    Stats,
+    Share,
+    /// Turn hard mode on or off, or toggle it.
+    Hard(Option<bool>),
+    Hint,
+    /// Take back the last guess.
+    Undo,
     Exit,
 }
 
+/// Number of single letter insertions, deletions, substitutions and swaps of
+/// adjacent letters that turn `a` into `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut d = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    d[0] = (0..=b.len()).collect();
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            d[i][j] = (d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1)
+                .min(d[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+    d[a.len()][b.len()]
+}
+
+/// The command closest to a mistyped one, if any is close enough.
+fn suggest(command: &str) -> Option<&'static str> {
+    event::COMMANDS
+        .iter()
+        .map(|c| (edit_distance(command, c), *c))
+        .filter(|(distance, _)| *distance <= 2)
+        .min()
+        .map(|(_, c)| c)
+}
+
 impl FromStr for Command {
     type Err = Error;
 
     fn from_str(com: &str) -> Result<Self, Self::Err> {
-        match com {
-            "/help" => Ok(Command::Help),
-            "/letters" => Ok(Command::Letters),
-            "/exit" => Ok(Command::Exit),
-            c if c.starts_with('/') => Err(Error::from(ErrorKind::InvalidCommand)),
-            guess => Ok(Command::Guess(String::from(guess))),
+        if !com.starts_with('/') {
+            return Ok(Command::Guess(String::from(com)));
+        }
+
+        let mut words = com.split_whitespace();
+        let name = words.next().unwrap_or_default();
+        let arg = words.next();
+        let usage = |usage| Err(Error::from(ErrorKind::CommandUsage(usage)));
+        if words.next().is_some() {
+            return match name {
+                "/new" => usage("/new [length]"),
+                "/hard" => usage("/hard [on|off]"),
+                _ => Err(Error::from(ErrorKind::UnknownCommand(
+                    String::from(name),
+                    suggest(name),
+                ))),
+            };
+        }
+
+        match (name, arg) {
+            ("/help", None) => Ok(Command::Help),
+            ("/letters", None) => Ok(Command::Letters),
+            ("/board", None) => Ok(Command::Board),
+            ("/new", None) => Ok(Command::New(None)),
+            ("/new", Some(length)) => match length.parse() {
+                Ok(length) if length > 0 => Ok(Command::New(Some(length))),
+                _ => usage("/new [length]"),
+            },
+            ("/giveup", None) => Ok(Command::GiveUp),
+            ("/stats", None) => Ok(Command::Stats),
+            ("/share", None) => Ok(Command::Share),
+            ("/hard", None) => Ok(Command::Hard(None)),
+            ("/hard", Some("on")) => Ok(Command::Hard(Some(true))),
+            ("/hard", Some("off")) => Ok(Command::Hard(Some(false))),
+            ("/hard", Some(_)) => usage("/hard [on|off]"),
+            ("/hint", None) => Ok(Command::Hint),
+            ("/undo", None) => Ok(Command::Undo),
+            ("/exit", None) => Ok(Command::Exit),
+            (name, Some(_)) if event::COMMANDS.contains(&name) => Err(Error::from(
+                ErrorKind::CommandUsage(event::COMMANDS.iter().find(|c| **c == name).unwrap()),
+            )),
+            (name, _) => Err(Error::from(ErrorKind::UnknownCommand(
+                String::from(name),
+                suggest(name),
+            ))),
         }
     }
 }
 
+/// Options accepted on the command line.
+#[derive(PartialEq, Eq, Debug, Default)]
+struct Args {
+    /// Play a practice game against this word.
+    word: Option<String>,
+
+    /// Play a practice game against a past daily puzzle.
+    puzzle: Option<u64>,
+
+    /// How the REPL prints its output.
+    format: Format,
+
+    /// Colors feedback is shown in.
+    palette: Palette,
+
+    /// Speak the engine line protocol instead of starting the REPL.
+    engine: bool,
+
+    /// Play full screen instead of in the line-based REPL.
+    tui: bool,
+
+    /// Save a transcript of the game to this file.
+    record: Option<String>,
+
+    /// Replay the transcript in this file instead of starting a game.
+    replay: Option<String>,
+
+    /// Export the replayed transcript as Markdown.
+    markdown: bool,
+
+    /// Run the chat bot on this IRC server instead of starting the REPL.
+    irc: Option<String>,
+
+    /// Nickname of the chat bot.
+    nick: Option<String>,
+
+    /// Channels the chat bot joins.
+    channels: Vec<String>,
+
+    /// File the bot and HTTP API keep daily results in.
+    leaderboard: Option<String>,
+
+    /// Serve the HTTP API instead of starting the REPL.
+    serve: bool,
+
+    /// Serve a REPL per TCP connection instead of starting the REPL.
+    telnet: bool,
+
+    /// Port the HTTP API, telnet server or race lobby listens on.
+    port: Option<u16>,
+
+    /// Host or join a multiplayer race instead of starting the REPL.
+    race: Option<Race>,
+
+    /// Name shown to the other players in a race.
+    name: Option<String>,
+
+    /// Language to play in, English if not given.
+    lang: Option<&'static Language>,
+
+    /// Pick random words from this difficulty band only.
+    difficulty: Option<Difficulty>,
+
+    /// Check the word list in this file instead of starting a game.
+    lint: Option<String>,
+
+    /// Word list the linted words must all appear in.
+    reference: Option<String>,
+
+    /// Write the linted list, without the problematic words, to this file.
+    write: Option<String>,
+
+    /// Play the next word the player hasn't been given yet.
+    next: bool,
+
+    /// File the words already given to the player are kept in, instead of
+    /// the default one under `$XDG_STATE_HOME`.
+    seen: Option<String>,
+
+    /// Play today's daily puzzle.
+    daily: bool,
+
+    /// Show how the solver finds the word instead of playing.
+    solve: bool,
+
+    /// Show the results of past daily puzzles instead of playing.
+    stats: bool,
+
+    /// Pick the word deterministically from this seed.
+    seed: Option<u64>,
+
+    /// Letters in the words played, 5 if not given.
+    length: Option<usize>,
+
+    /// Guesses allowed before the game is lost, unlimited if not given.
+    max_guesses: Option<usize>,
+
+    /// Require guesses to use every hint revealed so far.
+    hard: bool,
+
+    /// Play with the words in this file instead of the embedded list.
+    dict: Option<String>,
+
+    /// Print the usage instead of doing anything else.
+    help: bool,
+
+    /// Print the version instead of doing anything else.
+    version: bool,
+}
+
+/// Role in a multiplayer race.
+#[derive(PartialEq, Eq, Debug)]
+enum Race {
+    Host,
+    Join(String),
+}
+
+/// Word length played when `--length` isn't given.
+const DEFAULT_LENGTH: usize = 5;
+
+const USAGE: &str = "Usage: wordler [COMMAND] [OPTIONS]
+
+Commands:
+  play                    Play in the terminal (the default)
+  daily                   Play today's daily puzzle
+  solve                   Show how the solver finds the word
+  stats                   Show the results of your daily puzzles
+  serve                   Serve the HTTP API
+  telnet                  Serve a game to every TCP connection
+  race host|join <addr>   Host or join a multiplayer race
+  replay <file>           Replay a recorded game
+  bot --irc <host:port>   Run the IRC bot
+  dict lint <file>        Check a word list for problems
+
+Game options:
+  --word <word>           Practice against this word
+  --puzzle <number>       Practice a past daily puzzle
+  --seed <number>         Pick the word from a seed
+  --next                  Play the next word you haven't been given yet
+  --length <letters>      Play words of this length (5)
+  --max-guesses <count>   Lose after this many guesses (unlimited)
+  --hard, --no-hard       Guesses must (not) use every hint revealed so far
+  --difficulty <band>     Pick random words that are easy, medium or hard
+  --lang <code>           Play in en, es, fr, de or pt
+  --dict <file>           Play with the words in this file
+  --tui                   Play full screen
+  --format text|json      Output format of the REPL
+  --palette <palette>     Show feedback in classic or high-contrast colors
+  --record <file>         Save a transcript of the game
+  --seen <file>           File the words you have been given are kept in
+
+Other options:
+  --leaderboard <file>    File daily results are kept in
+  --port <port>           Port servers listen on
+  --name <name>           Your name in a race
+  --nick <nick>           Nickname of the IRC bot
+  --channel <channel>     Channel the IRC bot joins, repeatable
+  --markdown              Export a replayed game as Markdown
+  --engine                Speak the engine line protocol
+  --reference <file>      Word list linted words must appear in
+  --write <file>          Save the linted list without the flagged words
+  -h, --help              Print this help
+  -V, --version           Print the version
+
+Defaults for the game options can be set in $XDG_CONFIG_HOME/wordler/config.toml
+and in WORDLER_* environment variables, see the README.
+
+Exit status: 0 if the game was won, 1 if it was lost, 2 on errors.";
+
+/// Port the HTTP API listens on when `--port` isn't given.
+const DEFAULT_PORT: u16 = 7878;
+
+/// Port race lobbies listen on when `--port` isn't given.
+const DEFAULT_RACE_PORT: u16 = 7879;
+
+/// Port the telnet server listens on when `--port` isn't given.
+const DEFAULT_TELNET_PORT: u16 = 7777;
+
+impl Args {
+    /// Parses the command line over `parsed`, which holds the defaults.
+    fn parse<I: Iterator<Item = String>>(mut parsed: Args, mut args: I) -> Result<Self, Error> {
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--word" => {
+                    let word = args.next().ok_or_else(|| {
+                        Error::from(ErrorKind::InvalidArgument(String::from(
+                            "--word requires a value",
+                        )))
+                    })?;
+                    parsed.word = Some(word);
+                }
+                "--puzzle" => {
+                    let puzzle = args.next().and_then(|n| n.parse().ok()).ok_or_else(|| {
+                        Error::from(ErrorKind::InvalidArgument(String::from(
+                            "--puzzle requires a puzzle number",
+                        )))
+                    })?;
+                    parsed.puzzle = Some(puzzle);
+                }
+                "play" => {}
+                "daily" => parsed.daily = true,
+                "solve" => parsed.solve = true,
+                "stats" => parsed.stats = true,
+                "serve" => parsed.serve = true,
+                "telnet" => parsed.telnet = true,
+                "race" => {
+                    let usage = || {
+                        Error::from(ErrorKind::InvalidArgument(String::from(
+                            "usage: race host | race join <addr>",
+                        )))
+                    };
+                    parsed.race = match args.next().as_deref() {
+                        Some("host") => Some(Race::Host),
+                        Some("join") => Some(Race::Join(args.next().ok_or_else(usage)?)),
+                        _ => return Err(usage()),
+                    };
+                }
+                "--name" => {
+                    let name = args.next().ok_or_else(|| {
+                        Error::from(ErrorKind::InvalidArgument(String::from(
+                            "--name requires a value",
+                        )))
+                    })?;
+                    parsed.name = Some(name);
+                }
+                "--port" => {
+                    let port = args.next().and_then(|n| n.parse().ok()).ok_or_else(|| {
+                        Error::from(ErrorKind::InvalidArgument(String::from(
+                            "--port requires a port number",
+                        )))
+                    })?;
+                    parsed.port = Some(port);
+                }
+                "--engine" => parsed.engine = true,
+                "--tui" => parsed.tui = true,
+                "--record" => {
+                    let file = args.next().ok_or_else(|| {
+                        Error::from(ErrorKind::InvalidArgument(String::from(
+                            "--record requires a file",
+                        )))
+                    })?;
+                    parsed.record = Some(file);
+                }
+                "replay" => {
+                    let file = args.next().ok_or_else(|| {
+                        Error::from(ErrorKind::InvalidArgument(String::from(
+                            "usage: replay <file>",
+                        )))
+                    })?;
+                    parsed.replay = Some(file);
+                }
+                "--markdown" => parsed.markdown = true,
+                "dict" => {
+                    let usage = || {
+                        Error::from(ErrorKind::InvalidArgument(String::from(
+                            "usage: dict lint <file> [--reference <file>] [--write <file>]",
+                        )))
+                    };
+                    if args.next().as_deref() != Some("lint") {
+                        return Err(usage());
+                    }
+                    parsed.lint = Some(args.next().ok_or_else(usage)?);
+                }
+                "--reference" => {
+                    let file = args.next().ok_or_else(|| {
+                        Error::from(ErrorKind::InvalidArgument(String::from(
+                            "--reference requires a file",
+                        )))
+                    })?;
+                    parsed.reference = Some(file);
+                }
+                "--write" => {
+                    let file = args.next().ok_or_else(|| {
+                        Error::from(ErrorKind::InvalidArgument(String::from(
+                            "--write requires a file",
+                        )))
+                    })?;
+                    parsed.write = Some(file);
+                }
+                "bot" => {
+                    let usage = || {
+                        Error::from(ErrorKind::InvalidArgument(String::from(
+                            "usage: bot --irc <host:port> [--nick <nick>] --channel <channel>",
+                        )))
+                    };
+                    if args.next().as_deref() != Some("--irc") {
+                        return Err(usage());
+                    }
+                    parsed.irc = Some(args.next().ok_or_else(usage)?);
+                }
+                "--nick" => {
+                    let nick = args.next().ok_or_else(|| {
+                        Error::from(ErrorKind::InvalidArgument(String::from(
+                            "--nick requires a value",
+                        )))
+                    })?;
+                    parsed.nick = Some(nick);
+                }
+                "--leaderboard" => {
+                    let file = args.next().ok_or_else(|| {
+                        Error::from(ErrorKind::InvalidArgument(String::from(
+                            "--leaderboard requires a file",
+                        )))
+                    })?;
+                    parsed.leaderboard = Some(file);
+                }
+                "--channel" => {
+                    let channel = args.next().ok_or_else(|| {
+                        Error::from(ErrorKind::InvalidArgument(String::from(
+                            "--channel requires a value",
+                        )))
+                    })?;
+                    parsed.channels.push(channel);
+                }
+                "--lang" => {
+                    let code = args.next().ok_or_else(|| {
+                        Error::from(ErrorKind::InvalidArgument(String::from(
+                            "--lang requires a language code",
+                        )))
+                    })?;
+                    parsed.lang = Some(<&Language>::from_str(&code)?);
+                }
+                "--difficulty" => {
+                    let difficulty = args.next().ok_or_else(|| {
+                        Error::from(ErrorKind::InvalidArgument(String::from(
+                            "--difficulty requires easy, medium or hard",
+                        )))
+                    })?;
+                    parsed.difficulty = Some(Difficulty::from_str(&difficulty)?);
+                }
+                "--seed" => {
+                    let seed = args.next().and_then(|n| n.parse().ok()).ok_or_else(|| {
+                        Error::from(ErrorKind::InvalidArgument(String::from(
+                            "--seed requires a number",
+                        )))
+                    })?;
+                    parsed.seed = Some(seed);
+                }
+                "--length" => {
+                    let length = args
+                        .next()
+                        .and_then(|n| n.parse().ok())
+                        .filter(|&n| n > 0)
+                        .ok_or_else(|| {
+                            Error::from(ErrorKind::InvalidArgument(String::from(
+                                "--length requires a number of letters",
+                            )))
+                        })?;
+                    parsed.length = Some(length);
+                }
+                "--max-guesses" => {
+                    let max = args
+                        .next()
+                        .and_then(|n| n.parse().ok())
+                        .filter(|&n| n > 0)
+                        .ok_or_else(|| {
+                            Error::from(ErrorKind::InvalidArgument(String::from(
+                                "--max-guesses requires a number of guesses",
+                            )))
+                        })?;
+                    parsed.max_guesses = Some(max);
+                }
+                "--hard" => parsed.hard = true,
+                "--no-hard" => parsed.hard = false,
+                "--dict" => {
+                    let file = args.next().ok_or_else(|| {
+                        Error::from(ErrorKind::InvalidArgument(String::from(
+                            "--dict requires a file",
+                        )))
+                    })?;
+                    parsed.dict = Some(file);
+                }
+                "-h" | "--help" => {
+                    parsed.help = true;
+                    return Ok(parsed);
+                }
+                "-V" | "--version" => {
+                    parsed.version = true;
+                    return Ok(parsed);
+                }
+                "--next" => parsed.next = true,
+                "--seen" => {
+                    let file = args.next().ok_or_else(|| {
+                        Error::from(ErrorKind::InvalidArgument(String::from(
+                            "--seen requires a file",
+                        )))
+                    })?;
+                    parsed.seen = Some(file);
+                }
+                "--palette" => {
+                    let palette = args.next().ok_or_else(|| {
+                        Error::from(ErrorKind::InvalidArgument(String::from(
+                            "--palette requires classic or high-contrast",
+                        )))
+                    })?;
+                    parsed.palette = Palette::from_str(&palette)?;
+                }
+                "--format" => {
+                    let format = args.next().ok_or_else(|| {
+                        Error::from(ErrorKind::InvalidArgument(String::from(
+                            "--format requires a value",
+                        )))
+                    })?;
+                    parsed.format = Format::from_str(&format)?;
+                }
+                other => {
+                    return Err(Error::from(ErrorKind::InvalidArgument(format!(
+                        "unexpected argument {}",
+                        other
+                    ))))
+                }
+            }
+        }
+
+        if let (Some(Race::Join(_)), None) = (&parsed.race, &parsed.name) {
+            return Err(Error::from(ErrorKind::InvalidArgument(String::from(
+                "joining a race requires --name",
+            ))));
+        }
+
+        let words = [
+            parsed.word.is_some(),
+            parsed.puzzle.is_some(),
+            parsed.seed.is_some(),
+            parsed.next,
+            parsed.daily,
+        ];
+        if words.iter().filter(|&&given| given).count() > 1 {
+            return Err(Error::from(ErrorKind::InvalidArgument(String::from(
+                "only one of daily, --word, --puzzle, --seed and --next can be used",
+            ))));
+        }
+
+        Ok(parsed)
+    }
+
+    /// Opens the leaderboard file if one was given, otherwise results are only
+    /// kept in memory.
+    fn open_leaderboard(&self) -> Result<Leaderboard, Error> {
+        match &self.leaderboard {
+            Some(file) => Leaderboard::open(file),
+            None => Ok(Leaderboard::in_memory()),
+        }
+    }
+
+    /// Opens the file of words already given to the player, or keeps them in
+    /// memory only if there is nowhere to save them. Each difficulty deals
+    /// its own words in its own rounds, so by default each one has a file of
+    /// its own: finishing a round of hard words doesn't bring back the easy
+    /// ones.
+    fn open_seen(&self) -> Result<Seen, Error> {
+        let path = self.seen.as_ref().map(PathBuf::from);
+        let file = match self.difficulty {
+            Some(difficulty) => format!("seen-{}.txt", difficulty.name()),
+            None => String::from("seen.txt"),
+        };
+        match path.or_else(|| state_path(&file)) {
+            Some(file) => Seen::open(file),
+            None => Ok(Seen::in_memory()),
+        }
+    }
+
+    /// Opens the file the local player's daily results are kept in: the
+    /// leaderboard file if one was given, otherwise one in the state
+    /// directory.
+    fn open_results(&self) -> Result<Leaderboard, Error> {
+        let path = self.leaderboard.as_ref().map(PathBuf::from);
+        match path.or_else(|| state_path("results.tsv")) {
+            Some(file) => Leaderboard::open(file),
+            None => Ok(Leaderboard::in_memory()),
+        }
+    }
+
+    /// The words to play with: those of the chosen length from the `--dict`
+    /// file or the embedded list of the chosen language. The list lives as
+    /// long as the program, like the embedded ones.
+    fn open_dict(&self) -> Result<&'static WordList, Error> {
+        let language = self.lang.unwrap_or(&ENGLISH);
+        let length = self.length.unwrap_or(DEFAULT_LENGTH);
+        let (words, source) = match &self.dict {
+            Some(file) => (
+                WordList::parse_in(language, &fs::read_to_string(file)?),
+                file.as_str(),
+            ),
+            None if length == DEFAULT_LENGTH => return Ok(language.words()),
+            None => (language.words().clone(), language.name),
+        };
+
+        let words = words.filter(|w| w.letters().count() == length);
+        if words.is_empty() {
+            return Err(Error::from(ErrorKind::InvalidArgument(format!(
+                "no {}-letter words in {}",
+                length, source
+            ))));
+        }
+        Ok(Box::leak(Box::new(words)))
+    }
+
+    /// Builds the game described by the arguments.
+    fn game(&self, dict: &WordList) -> Result<Game, Error> {
+        let mut game = match (&self.word, self.puzzle, self.seed) {
+            (Some(word), _, _) => Game::with_word(dict, word)?,
+            (None, Some(puzzle), _) => Game::with_puzzle(dict, puzzle)?,
+            (None, None, Some(seed)) => Game::with_seed(dict, seed),
+            (None, None, None) if self.daily => Game::daily(dict)?,
+            (None, None, None) if self.next => {
+                let mut seen = self.open_seen()?;
+                match self.difficulty {
+                    Some(difficulty) => Game::next(&difficulty.answers(dict)?, &mut seen)?,
+                    None => Game::next(dict, &mut seen)?,
+                }
+            }
+            (None, None, None) => match self.difficulty {
+                Some(difficulty) => Game::from(&difficulty.answers(dict)?),
+                None => Game::from(dict),
+            },
+        };
+        game.max_guesses = self.max_guesses;
+        game.hard = self.hard;
+        Ok(game)
+    }
+}
+
+/// Binds a listener for one of the server modes, exiting if the port is
+/// unavailable.
+fn bind(host: &str, port: u16) -> TcpListener {
+    match TcpListener::bind((host, port)) {
+        Ok(listener) => listener,
+        Err(e) => {
+            eprintln!("{}", Error::from(e));
+            process::exit(2);
+        }
+    }
+}
+
+/// Prints how the solver finds the word of the game, and returns whether it
+/// did within the allowed guesses.
+fn solve<W: Write>(dict: &WordList, game: &Game, mut out: W) -> io::Result<bool> {
+    let answer = Word::parse(&game.word).unwrap_or_default();
+    let guesses = difficulty::guesses(dict, &answer);
+    for guess in &guesses {
+        writeln!(out, "{} {}", guess, guess.score(&answer))?;
+    }
+
+    let solved =
+        guesses.last() == Some(&answer) && game.max_guesses.is_none_or(|max| guesses.len() <= max);
+    if solved {
+        writeln!(out, "Solved in {} guesses.", guesses.len())?;
+    } else {
+        writeln!(out, "Not solved, the word was {}.", game.word)?;
+    }
+    Ok(solved)
+}
+
+/// Saves the result of a daily puzzle played locally, so `stats` can show it.
+fn record_daily(args: &Args, game: &Game) -> Result<(), Error> {
+    let Some(puzzle) = game.puzzle.filter(|_| game.mode == Mode::Daily) else {
+        return Ok(());
+    };
+    let user = env::var("USER").unwrap_or_else(|_| String::from("you"));
+    let record = leaderboard::Record {
+        user,
+        puzzle,
+        guesses: game.history.len(),
+        won: game.won(),
+    };
+    match args.open_results()?.record(record) {
+        Err(Error {
+            kind: ErrorKind::AlreadyPlayed(_),
+        }) => Ok(()),
+        result => result,
+    }
+}
+
 fn main() {
-    let wordle = Game::from(String::from(DICT));
-    wordle.repl().unwrap();
+    let args = match Config::load().and_then(|c| Args::parse(c.args(), env::args().skip(1))) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(2);
+        }
+    };
+    if args.help {
+        println!("{}", USAGE);
+        return;
+    }
+    if args.version {
+        println!("wordler {}", env!("CARGO_PKG_VERSION"));
+        return;
+    }
+    if let Some(file) = &args.lint {
+        let language = args.lang.unwrap_or(&ENGLISH);
+        match lint::run(
+            file,
+            language,
+            args.reference.as_deref(),
+            args.write.as_deref(),
+        ) {
+            Ok(true) => return,
+            Ok(false) => process::exit(1),
+            Err(e) => {
+                eprintln!("{}", e);
+                process::exit(2);
+            }
+        }
+    }
+    if args.stats {
+        match args.open_results() {
+            Ok(results) => {
+                let standings = results.standings(Period::AllTime, today_puzzle());
+                println!("{}", leaderboard::render(&standings));
+                return;
+            }
+            Err(e) => {
+                eprintln!("{}", e);
+                process::exit(2);
+            }
+        }
+    }
+
+    let dict = match args.open_dict() {
+        Ok(dict) => dict,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(2);
+        }
+    };
+    if let Some(file) = &args.replay {
+        let result = Transcript::load(file).and_then(|transcript| {
+            if args.markdown {
+                print!("{}", transcript.to_markdown());
+                Ok(())
+            } else {
+                transcript::replay(&transcript, dict, io::stdin().lock(), io::stdout())
+            }
+        });
+        if let Err(e) = result {
+            eprintln!("{}", e);
+            process::exit(2);
+        }
+        return;
+    }
+    if let Some(server) = &args.irc {
+        let nick = args.nick.as_deref().unwrap_or("wordler");
+        let result = args.open_leaderboard().and_then(|leaderboard| {
+            let mut irc = bot::Irc::connect(server.as_str(), nick, &args.channels)?;
+            bot::Bot::new(dict, leaderboard).run(&mut irc)
+        });
+        if let Err(e) = result {
+            eprintln!("{}", e);
+            process::exit(2);
+        }
+        return;
+    }
+    if args.engine {
+        if let Err(e) = Engine::new(dict).run() {
+            eprintln!("{}", e);
+            process::exit(2);
+        }
+        return;
+    }
+    match &args.race {
+        Some(Race::Host) => {
+            let port = args.port.unwrap_or(DEFAULT_RACE_PORT);
+            let listener = bind("0.0.0.0", port);
+            println!("race lobby listening on port {}", port);
+            if let Err(e) = race::host(listener, dict, race::IDLE_TIMEOUT) {
+                eprintln!("{}", e);
+                process::exit(2);
+            }
+            return;
+        }
+        Some(Race::Join(addr)) => {
+            let name = args.name.as_deref().unwrap_or_default();
+            if let Err(e) = race::join(addr.as_str(), name) {
+                eprintln!("{}", e);
+                process::exit(2);
+            }
+            return;
+        }
+        None => {}
+    }
+    if args.telnet {
+        let port = args.port.unwrap_or(DEFAULT_TELNET_PORT);
+        let listener = bind("0.0.0.0", port);
+        println!("play with: nc <host> {}", port);
+        if let Err(e) = telnet::listen(listener, dict, telnet::IDLE_TIMEOUT) {
+            eprintln!("{}", e);
+            process::exit(2);
+        }
+        return;
+    }
+    if args.serve {
+        let port = args.port.unwrap_or(DEFAULT_PORT);
+        let listener = bind("127.0.0.1", port);
+        let leaderboard = match args.open_leaderboard() {
+            Ok(leaderboard) => leaderboard,
+            Err(e) => {
+                eprintln!("{}", e);
+                process::exit(2);
+            }
+        };
+        println!("listening on http://127.0.0.1:{}", port);
+        if let Err(e) = serve::serve(listener, dict, leaderboard) {
+            eprintln!("{}", e);
+            process::exit(2);
+        }
+        return;
+    }
+
+    let mut wordle = match args.game(dict) {
+        Ok(game) => game,
+        Err(e) => {
+            if let Some(line) = Event::Error(&e).render(args.format, args.palette) {
+                eprintln!("{}", line);
+            }
+            process::exit(2);
+        }
+    };
+    if args.solve {
+        match solve(dict, &wordle, io::stdout()) {
+            Ok(true) => return,
+            Ok(false) => process::exit(1),
+            Err(e) => {
+                eprintln!("{}", Error::from(e));
+                process::exit(2);
+            }
+        }
+    }
+
+    if args.tui {
+        wordle = match tui::run(dict, wordle, args.palette) {
+            Ok(game) => game,
+            Err(e) => {
+                eprintln!("{}", e);
+                process::exit(2);
+            }
+        };
+    } else {
+        let mut sink = Writer::new(io::stdout(), args.format).with_palette(args.palette);
+        let input: Box<dyn BufRead> = if args.format == Format::Text && io::stdin().is_terminal() {
+            let words = if wordle.practice() {
+                dict.iter().map(|w| w.to_string()).collect()
+            } else {
+                vec![]
+            };
+            Box::new(LineEditor::new(
+                io::stdin(),
+                io::stdout(),
+                "> ",
+                &event::COMMANDS,
+                words,
+            ))
+        } else {
+            Box::new(io::stdin().lock())
+        };
+        let result = match &args.record {
+            Some(file) => {
+                let transcript = Transcript::new(&wordle, dict);
+                let mut recorder = Recorder::new(&mut sink, transcript);
+                wordle
+                    .repl(dict, input, &mut recorder)
+                    .and_then(|()| recorder.transcript.save(file))
+            }
+            None => wordle.repl(dict, input, &mut sink),
+        };
+        if let Err(e) = result {
+            eprintln!("{}", e);
+            process::exit(2);
+        }
+    }
+
+    if let Err(e) = record_daily(&args, &wordle) {
+        eprintln!("{}", e);
+        process::exit(2);
+    }
+    if !wordle.won() {
+        process::exit(1);
+    }
 }
 
 #[cfg(test)]
@@ -313,7 +1857,7 @@ mod test {
 
     #[test]
     fn test_wordle() {
-        let mut wordle = Game::from(String::from("fudge"));
+        let mut wordle = Game::new(String::from("fudge"));
 
         assert_eq!(
             wordle.guess(String::from("reads")).unwrap(),
@@ -343,7 +1887,7 @@ mod test {
 
     #[test]
     fn test_doubles() {
-        let mut wordle = Game::from(String::from("sassy"));
+        let mut wordle = Game::new(String::from("sassy"));
 
         assert_eq!(
             wordle.guess(String::from("space")).unwrap(),
@@ -359,9 +1903,26 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_language() {
+        let dict = lang::SPANISH.words();
+        let mut wordle = Game::with_word(dict, "SEÑAL").unwrap();
+        assert_eq!(wordle.word, "señal");
+        assert_eq!(wordle.used_letters.len(), 27);
+
+        assert_eq!(
+            wordle.guess(String::from("Árbol")).unwrap().pattern(),
+            "10002"
+        );
+        assert_eq!(wordle.history[0].0, "arbol");
+        assert!(wordle.guess(String::from("niño")).is_err());
+        assert!(wordle.guess(String::from("Señal")).unwrap().correct());
+        assert!(wordle.used_letters[&'ñ']);
+    }
+
     #[test]
     fn test_out_of_bounds() {
-        let mut wordle = Game::from(String::from("fudge"));
+        let mut wordle = Game::new(String::from("fudge"));
 
         assert!(wordle.guess(String::from("lodging")).is_err());
         assert!(wordle.guess(String::from("lol")).is_err());
@@ -369,7 +1930,7 @@ mod test {
 
     #[test]
     fn test_uppercase() {
-        let mut wordle = Game::from(String::from("fudge"));
+        let mut wordle = Game::new(String::from("fudge"));
         assert_eq!(
             wordle.guess(String::from("FUDGE")).unwrap(),
             Guess {
@@ -384,9 +1945,92 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_keyboard() {
+        let mut wordle = Game::new(String::from("fudge"));
+        let _ = wordle.guess(String::from("lodge"));
+        let _ = wordle.guess(String::from("dunce"));
+
+        let keyboard = wordle.keyboard();
+        assert_eq!(keyboard.get(&'l'), Some(&GuessChar::Absent));
+        assert_eq!(keyboard.get(&'d'), Some(&GuessChar::Correct));
+        assert_eq!(keyboard.get(&'u'), Some(&GuessChar::Correct));
+        assert_eq!(keyboard.get(&'z'), None);
+    }
+
+    #[test]
+    fn test_share() {
+        let mut wordle = Game::daily(&WordList::from("fudge")).unwrap();
+        let _ = wordle.guess(String::from("reads"));
+        let _ = wordle.guess(String::from("fudge"));
+
+        assert_eq!(
+            wordle.share(Palette::Classic),
+            format!("Wordler #{} 2\n⬛🟨⬛🟨⬛\n🟩🟩🟩🟩🟩", today_puzzle())
+        );
+
+        let mut wordle = Game::new(String::from("fudge"));
+        let _ = wordle.guess(String::from("reads"));
+        assert_eq!(wordle.share(Palette::Classic), "Wordler X\n⬛🟨⬛🟨⬛");
+        assert_eq!(wordle.share(Palette::HighContrast), "Wordler X\n⬛🟦⬛🟦⬛");
+    }
+
+    #[test]
+    fn test_invalid_characters() {
+        let mut wordle = Game::new(String::from("fudge"));
+        let letters = wordle.used_letters.clone();
+        let e = wordle.guess(String::from("ab1!x")).unwrap_err();
+        assert_eq!(e.to_string(), "'1' at position 3 is not a letter.");
+        let e = wordle.guess(String::from("héllo")).unwrap_err();
+        assert_eq!(e.to_string(), "'é' at position 2 is not a letter.");
+        let e = wordle.guess(String::from("ab")).unwrap_err();
+        assert_eq!(e.kind().code(), "guess_too_short");
+        let e = wordle.guess(String::from("a b")).unwrap_err();
+        assert_eq!(e.kind().code(), "invalid_character");
+        assert_eq!(wordle.used_letters, letters);
+        assert!(wordle.history.is_empty());
+
+        // Accents the language folds are still letters.
+        let mut wordle = Game::new(String::from("hello"));
+        wordle.language = &lang::FRENCH;
+        assert!(wordle.guess(String::from("HÉLLO")).unwrap().correct());
+    }
+
+    #[test]
+    fn test_undo() {
+        let dict = WordList::from("fudge\nreads\nlodge");
+        let mut wordle = Game::with_word(&dict, "fudge").unwrap();
+        assert_eq!(wordle.undo().unwrap_err().kind().code(), "nothing_to_undo");
+
+        wordle.guess(String::from("reads")).unwrap();
+        let letters = wordle.used_letters.clone();
+        wordle.guess(String::from("lodge")).unwrap();
+        assert_eq!(wordle.undo().unwrap(), "lodge");
+        assert_eq!(wordle.history.len(), 1);
+        // D and E were in both guesses and stay used.
+        assert_eq!(wordle.used_letters, letters);
+        assert!(wordle.used_letters[&'d']);
+        assert!(!wordle.used_letters[&'l']);
+
+        let mut puzzle = Game::with_puzzle(&dict, 1).unwrap();
+        puzzle.guess(String::from("reads")).unwrap();
+        assert!(puzzle.undo().is_ok());
+
+        for mut game in [
+            Game::daily(&dict).unwrap(),
+            Game::from(&dict),
+            Game::with_seed(&dict, 1),
+        ] {
+            game.guess(String::from("reads")).unwrap();
+            let e = game.undo().unwrap_err();
+            assert_eq!(e.kind().code(), "undo_unavailable");
+            assert_eq!(game.history.len(), 1);
+        }
+    }
+
     #[test]
     fn test_used_letters() {
-        let mut wordle = Game::from(String::from("fudge"));
+        let mut wordle = Game::new(String::from("fudge"));
         let _ = wordle.guess(String::from("cream"));
 
         assert_eq!(
@@ -408,4 +2052,447 @@ mod test {
             8
         );
     }
+
+    #[test]
+    fn test_practice_word() {
+        let wordle = Game::with_word(&WordList::from("fudge\nlodge"), "LODGE").unwrap();
+        assert_eq!(wordle.word, "lodge");
+        assert!(wordle.practice());
+
+        assert!(Game::with_word(&WordList::from("fudge\nlodge"), "reads").is_err());
+    }
+
+    #[test]
+    fn test_practice_puzzle() {
+        let first = Game::with_puzzle(ENGLISH.words(), 123).unwrap();
+        let second = Game::with_puzzle(ENGLISH.words(), 123).unwrap();
+        assert_eq!(first.word, second.word);
+        assert!(first.practice());
+
+        assert!(Game::with_puzzle(ENGLISH.words(), today_puzzle() + 1).is_err());
+    }
+
+    #[test]
+    fn test_next() {
+        let dict = WordList::from(
+            "fudge
+lodge",
+        );
+        let mut seen = Seen::in_memory();
+        let first = Game::next(&dict, &mut seen).unwrap();
+        assert_eq!(first.mode, Mode::Next);
+        assert!(!first.practice());
+
+        let second = Game::next(&dict, &mut seen).unwrap();
+        assert_ne!(first.word, second.word);
+
+        // Both words were seen, so a new round starts.
+        let third = Game::next(&dict, &mut seen).unwrap();
+        assert!(seen.contains(&third.word));
+
+        let empty = WordList::from("");
+        assert!(Game::next(&empty, &mut seen).is_err());
+        assert!(Game::daily(&empty).is_err());
+        assert!(Game::with_puzzle(&empty, 1).is_err());
+    }
+
+    #[test]
+    fn test_args() {
+        let args = |a: &[&str]| Args::parse(Args::default(), a.iter().map(|s| s.to_string()));
+
+        assert_eq!(args(&[]).unwrap(), Args::default());
+        assert_eq!(
+            args(&["--word", "fudge"]).unwrap().word,
+            Some(String::from("fudge"))
+        );
+        assert_eq!(args(&["--puzzle", "123"]).unwrap().puzzle, Some(123));
+        assert_eq!(args(&["--format", "json"]).unwrap().format, Format::Json);
+        assert!(args(&["--format", "yaml"]).is_err());
+        assert!(args(&["--engine"]).unwrap().engine);
+        assert!(args(&["--tui", "--word", "fudge"]).unwrap().tui);
+        assert_eq!(
+            args(&["--record", "game.json"]).unwrap().record,
+            Some(String::from("game.json"))
+        );
+        let replay = args(&["replay", "game.json", "--markdown"]).unwrap();
+        assert_eq!(replay.replay, Some(String::from("game.json")));
+        assert!(replay.markdown);
+        assert!(args(&["replay"]).is_err());
+        let bot = args(&[
+            "bot",
+            "--irc",
+            "irc.local:6667",
+            "--channel",
+            "#a",
+            "--channel",
+            "#b",
+        ])
+        .unwrap();
+        assert_eq!(bot.irc, Some(String::from("irc.local:6667")));
+        assert_eq!(bot.channels, vec!["#a", "#b"]);
+        assert!(args(&["bot"]).is_err());
+        assert_eq!(
+            args(&["serve", "--leaderboard", "results.tsv"])
+                .unwrap()
+                .leaderboard,
+            Some(String::from("results.tsv"))
+        );
+        let serve = args(&["serve", "--port", "8080"]).unwrap();
+        assert!(serve.serve);
+        assert_eq!(serve.port, Some(8080));
+        assert!(args(&["serve", "--port", "http"]).is_err());
+        assert!(args(&["telnet"]).unwrap().telnet);
+        let host = args(&["race", "host", "--port", "9000"]).unwrap();
+        assert_eq!(host.race, Some(Race::Host));
+        assert_eq!(host.port, Some(9000));
+        assert_eq!(
+            args(&["race", "join", "10.0.0.2:7879", "--name", "ana"])
+                .unwrap()
+                .race,
+            Some(Race::Join(String::from("10.0.0.2:7879")))
+        );
+        assert!(args(&["race", "join", "10.0.0.2:7879"]).is_err());
+        assert!(args(&["race"]).is_err());
+        assert!(args(&["--puzzle", "abc"]).is_err());
+        assert!(args(&["--word", "fudge", "--puzzle", "1"]).is_err());
+        assert!(args(&["--nope"]).is_err());
+        assert_eq!(args(&["--lang", "fr"]).unwrap().lang, Some(&lang::FRENCH));
+        assert!(args(&["--lang", "xx"]).is_err());
+        assert!(args(&["--lang"]).is_err());
+        assert_eq!(
+            args(&["--difficulty", "hard"]).unwrap().difficulty,
+            Some(Difficulty::Hard)
+        );
+        assert!(args(&["--difficulty", "extreme"]).is_err());
+        let lint = args(&["dict", "lint", "words.txt", "--write", "clean.txt"]).unwrap();
+        assert_eq!(lint.lint, Some(String::from("words.txt")));
+        assert_eq!(lint.write, Some(String::from("clean.txt")));
+        assert!(args(&["dict", "words.txt"]).is_err());
+        assert!(args(&["dict", "lint"]).is_err());
+        let next = args(&["--next", "--seen", "seen.txt"]).unwrap();
+        assert!(next.next);
+        assert_eq!(next.seen, Some(String::from("seen.txt")));
+        assert!(args(&["--next", "--word", "fudge"]).is_err());
+
+        let play = args(&["play", "--seed", "42", "--max-guesses", "6", "--hard"]).unwrap();
+        assert_eq!(play.seed, Some(42));
+        assert_eq!(play.max_guesses, Some(6));
+        assert!(play.hard);
+        assert!(args(&["daily"]).unwrap().daily);
+        assert!(args(&["daily", "--seed", "1"]).is_err());
+        assert!(args(&["solve", "--word", "fudge"]).unwrap().solve);
+        assert!(args(&["stats"]).unwrap().stats);
+        assert_eq!(args(&["--length", "6"]).unwrap().length, Some(6));
+        assert!(args(&["--length", "0"]).is_err());
+        assert!(args(&["--max-guesses", "many"]).is_err());
+        assert_eq!(
+            args(&["--dict", "words.txt"]).unwrap().dict,
+            Some(String::from("words.txt"))
+        );
+        assert!(args(&["--nope", "--help"]).is_err());
+        assert!(args(&["--help", "--nope"]).unwrap().help);
+        assert!(args(&["-V"]).unwrap().version);
+    }
+
+    #[test]
+    fn test_open_dict() {
+        let dict = Args::default().open_dict().unwrap();
+        assert!(std::ptr::eq(dict, ENGLISH.words()));
+
+        let path = env::temp_dir().join(format!("wordler-dict-{}.txt", std::process::id()));
+        fs::write(&path, "fudge\nbanana\nsquash\n").unwrap();
+        let file = Args {
+            dict: path.to_str().map(String::from),
+            length: Some(6),
+            ..Args::default()
+        };
+        let words: Vec<String> = file
+            .open_dict()
+            .unwrap()
+            .iter()
+            .map(|w| w.to_string())
+            .collect();
+        assert_eq!(words, ["banana", "squash"]);
+
+        let seven = Args {
+            length: Some(7),
+            ..file
+        };
+        assert!(seven.open_dict().is_err());
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_hard_mode() {
+        let mut wordle = Game::new(String::from("fudge"));
+        wordle.hard = true;
+        wordle.guess(String::from("lodge")).unwrap();
+
+        let e = wordle.guess(String::from("reads")).unwrap_err();
+        assert_eq!(e.to_string(), "hard mode: letter 3 must be D.");
+        let e = wordle.guess(String::from("guide")).unwrap_err();
+        assert_eq!(e.kind().code(), "hard_mode");
+        wordle.guess(String::from("nudge")).unwrap();
+        assert_eq!(wordle.history.len(), 2);
+
+        let mut wordle = Game::new(String::from("fudge"));
+        wordle.hard = true;
+        wordle.guess(String::from("reads")).unwrap();
+        let e = wordle.guess(String::from("lofty")).unwrap_err();
+        assert_eq!(e.to_string(), "hard mode: guess must contain E.");
+    }
+
+    #[test]
+    fn test_max_guesses() {
+        let mut wordle = Game::new(String::from("fudge"));
+        wordle.max_guesses = Some(2);
+        let mut output = vec![];
+        wordle
+            .repl(
+                &WordList::from("fudge"),
+                "reads\nlodge\nfudge\n".as_bytes(),
+                &mut Writer::new(&mut output, Format::Json),
+            )
+            .unwrap();
+        assert_eq!(wordle.history.len(), 2);
+        assert!(!wordle.won());
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains(
+            r#"{"event":"outcome","won":false,"word":"fudge","guesses":2}
+"#
+        ));
+    }
+
+    #[test]
+    fn test_solve() {
+        let dict = WordList::from("fudge\nlodge\nreads");
+        let mut game = Game::with_word(&dict, "reads").unwrap();
+        let mut output = vec![];
+        let solved = solve(&dict, &game, &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(solved, output.ends_with(" guesses.\n"));
+        assert!(output.contains("reads 🟩🟩🟩🟩🟩"));
+
+        game.max_guesses = Some(1);
+        let guesses = difficulty::guesses(&dict, &Word::parse("reads").unwrap());
+        assert_eq!(solve(&dict, &game, io::sink()).unwrap(), guesses.len() == 1);
+    }
+
+    /// Plays a game against `word` with the given input and returns everything
+    /// the REPL wrote.
+    fn transcript(word: &str, input: &str, format: Format) -> String {
+        let mut output = vec![];
+        Game::new(String::from(word))
+            .repl(
+                &WordList::from(word),
+                input.as_bytes(),
+                &mut Writer::new(&mut output, format),
+            )
+            .unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn test_transcript() {
+        assert_eq!(
+            transcript("fudge", "reads\nlol\n/letters\nFUDGE\n", Format::Text),
+            format!(
+                "{}\n\
+                > ⬛🟨⬛🟨⬛\n\
+                > guess too short, guesses must be 5 letters.\n\
+                > b c f g h i j k l m n o p q t u v w x y z\n\
+                > 🟩🟩🟩🟩🟩\n\
+                Congrats! 🎉\n\
+                How each guess did:\n\
+                1. READS 1 → 1 word, 0.0 bits (0.0 expected), the solver's FUDGE expected 0.0\n\
+                2. FUDGE 1 → 1 word, 0.0 bits (0.0 expected), as the solver would\n",
+                event::help(5)
+            )
+        );
+    }
+
+    #[test]
+    fn test_transcript_end_of_input() {
+        assert_eq!(
+            transcript("fudge", "/bogus\n", Format::Text),
+            format!(
+                "{}\n> unknown command /bogus. use /help to list all available commands\n> ",
+                event::help(5)
+            )
+        );
+    }
+
+    #[test]
+    fn test_transcript_json() {
+        let output = transcript("fudge", "lodge\n/exit\nfudge\n", Format::Json);
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(
+            lines[0],
+            r#"{"event":"start","length":5,"practice":false,"puzzle":null}"#
+        );
+        assert!(lines[1].starts_with(r#"{"event":"guess","word":"lodge""#));
+        assert_eq!(
+            lines[2],
+            r#"{"event":"outcome","won":false,"word":"fudge","guesses":1}"#
+        );
+        assert_eq!(
+            lines[3],
+            concat!(
+                r#"{"event":"analysis","guesses":[{"word":"lodge","before":1,"after":1,"#,
+                r#""bits":0.00,"expected_bits":0.00,"solver":"fudge","solver_expected_bits":0.00,"#,
+                r#""lucky":false,"hard_mode":true}]}"#
+            )
+        );
+    }
+
+    #[test]
+    fn test_error() {
+        use std::error::Error as _;
+
+        let e = Error::from(ErrorKind::NotInWordList(String::from("abcde")));
+        assert_eq!(e.kind().code(), "not_in_word_list");
+        assert_eq!(
+            format!("{:?}", e),
+            r#"Error { kind: NotInWordList("abcde") }"#
+        );
+        assert!(e.source().is_none());
+
+        let e = Error::from(io::Error::new(io::ErrorKind::NotFound, "gone"));
+        assert_eq!(e.source().unwrap().to_string(), "gone");
+        assert!(!e.retryable());
+
+        let e = Error::from(ErrorKind::InvalidCharacter {
+            character: '1',
+            position: 3,
+        });
+        assert_eq!(e.to_string(), "'1' at position 3 is not a letter.");
+        assert_eq!(e.kind().code(), "invalid_character");
+    }
+
+    #[test]
+    fn test_daily_commands() {
+        let dict = WordList::from("fudge\nlodge");
+        let mut game = Game::daily(&dict).unwrap();
+        let word = game.word.clone();
+        let mut output = vec![];
+        game.repl(
+            &dict,
+            "/hint\n/new\n".as_bytes(),
+            &mut Writer::new(&mut output, Format::Text),
+        )
+        .unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("> /hint isn't available in the daily puzzle.\n"));
+        assert!(output.contains("> /new isn't available in the daily puzzle.\n"));
+        assert!(!output.contains(&word.to_uppercase()));
+        assert_eq!(game.mode, Mode::Daily);
+        assert_eq!(game.word, word);
+    }
+
+    #[test]
+    fn test_commands() {
+        assert_eq!(Command::from_str("/new 6").unwrap(), Command::New(Some(6)));
+        assert_eq!(
+            Command::from_str("/hard off").unwrap(),
+            Command::Hard(Some(false))
+        );
+        assert_eq!(Command::from_str("/board").unwrap(), Command::Board);
+
+        let e = Command::from_str("/new six").unwrap_err();
+        assert_eq!(e.to_string(), "usage: /new [length]");
+        let e = Command::from_str("/board 2").unwrap_err();
+        assert_eq!(e.to_string(), "usage: /board");
+        let e = Command::from_str("/hnit").unwrap_err();
+        assert_eq!(e.to_string(), "unknown command /hnit, did you mean /hint?");
+        assert_eq!(e.kind().code(), "unknown_command");
+        assert!(e.retryable());
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("/stast", "/stats"), 1);
+        assert_eq!(edit_distance("/hnit", "/exit"), 2);
+        assert_eq!(edit_distance("/exit", "/exit"), 0);
+        assert_eq!(edit_distance("", "/new"), 4);
+        assert_eq!(suggest("/leters"), Some("/letters"));
+        assert_eq!(suggest("/bogus"), None);
+    }
+
+    #[test]
+    fn test_session_commands() {
+        let dict = WordList::from("fudge\nlodge\nnudge\nreads\nbreads");
+        let mut game = Game::with_word(&dict, "fudge").unwrap();
+        let mut output = vec![];
+        let input = "lodge\n/hint\n/board\n/hard\n/new 6\n/stats\n/giveup\n";
+        game.repl(
+            &dict,
+            input.as_bytes(),
+            &mut Writer::new(&mut output, Format::Text),
+        )
+        .unwrap();
+        let output = String::from_utf8(output).unwrap();
+
+        assert!(output.contains("> 2 words still fit. Letter 1 is F.\n"));
+        assert!(output.contains("> ⬛⬛🟩🟩🟩 lodge\n"));
+        assert!(output.contains("> Hard mode is on.\n"));
+        assert!(output.contains("> The word was FUDGE.\n"));
+        assert!(output.contains("> Played 1, won 0, current streak 0, max streak 0.\n"));
+        assert!(output.ends_with("> The word was BREADS.\n"));
+        assert_eq!(game.word, "breads");
+        assert!(game.hard);
+        assert_eq!(game.tally.played, 2);
+    }
+
+    /// Keeps the kind of every event it receives.
+    #[derive(Default)]
+    struct Recorder {
+        events: Vec<&'static str>,
+        prompts: usize,
+    }
+
+    impl Sink for Recorder {
+        fn event(&mut self, event: &Event) -> io::Result<()> {
+            self.events.push(match event {
+                Event::Start { .. } => "start",
+                Event::Help { .. } => "help",
+                Event::Letters(_) => "letters",
+                Event::Guess { .. } => "guess",
+                Event::Board(_) => "board",
+                Event::Reveal(_) => "reveal",
+                Event::Stats(_) => "stats",
+                Event::Share(_) => "share",
+                Event::Hard(_) => "hard",
+                Event::Hint { .. } => "hint",
+                Event::Undo { .. } => "undo",
+                Event::Analysis(_) => "analysis",
+                Event::Error(_) => "error",
+                Event::Outcome { .. } => "outcome",
+            });
+            Ok(())
+        }
+
+        fn prompt(&mut self) -> io::Result<()> {
+            self.prompts += 1;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_event_sink() {
+        let mut recorder = Recorder::default();
+        Game::new(String::from("fudge"))
+            .repl(
+                &WordList::from("fudge"),
+                "/help\nab\nfudge\nreads\n".as_bytes(),
+                &mut recorder,
+            )
+            .unwrap();
+
+        assert_eq!(
+            recorder.events,
+            vec!["start", "help", "error", "guess", "outcome", "analysis"]
+        );
+        assert_eq!(recorder.prompts, 3);
+    }
 }
@@ -0,0 +1,79 @@
+//! Colors feedback is shown in. The high contrast palette shows correct
+//! letters in orange and misplaced ones in blue, which are easier to tell
+//! apart than green and yellow with the common kinds of color blindness.
+
+use std::str::FromStr;
+
+use crate::{Error, ErrorKind, Guess, GuessChar};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub(crate) enum Palette {
+    #[default]
+    Classic,
+    HighContrast,
+}
+
+impl FromStr for Palette {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "classic" => Ok(Palette::Classic),
+            "high-contrast" => Ok(Palette::HighContrast),
+            other => Err(Error::from(ErrorKind::InvalidArgument(format!(
+                "unknown palette {}, expected classic or high-contrast",
+                other
+            )))),
+        }
+    }
+}
+
+impl Palette {
+    /// Emoji square for a letter's feedback.
+    pub(crate) fn square(self, result: GuessChar) -> &'static str {
+        match (self, result) {
+            (_, GuessChar::Absent) => "⬛",
+            (Palette::Classic, GuessChar::OutOfPlace) => "🟨",
+            (Palette::Classic, GuessChar::Correct) => "🟩",
+            (Palette::HighContrast, GuessChar::OutOfPlace) => "🟦",
+            (Palette::HighContrast, GuessChar::Correct) => "🟧",
+        }
+    }
+
+    /// One square per letter of a guess.
+    pub(crate) fn squares(self, guess: &Guess) -> String {
+        guess.inner.iter().map(|&r| self.square(r)).collect()
+    }
+
+    /// Terminal escape code coloring a tile with a letter's feedback.
+    pub(crate) fn ansi(self, result: GuessChar) -> &'static str {
+        match (self, result) {
+            (_, GuessChar::Absent) => "\x1b[1;97;100m",
+            (Palette::Classic, GuessChar::OutOfPlace) => "\x1b[1;97;43m",
+            (Palette::Classic, GuessChar::Correct) => "\x1b[1;97;42m",
+            (Palette::HighContrast, GuessChar::OutOfPlace) => "\x1b[1;97;44m",
+            (Palette::HighContrast, GuessChar::Correct) => "\x1b[1;97;48;5;208m",
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_squares() {
+        let guess = Guess::from_pattern("01202").unwrap();
+        assert_eq!(Palette::Classic.squares(&guess), guess.to_string());
+        assert_eq!(Palette::HighContrast.squares(&guess), "⬛🟦🟧⬛🟧");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            Palette::from_str("high-contrast").unwrap(),
+            Palette::HighContrast
+        );
+        assert!(Palette::from_str("neon").is_err());
+    }
+}
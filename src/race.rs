@@ -0,0 +1,626 @@
+//! Multiplayer races over TCP: every player in the lobby gets the same secret
+//! word and sees the others' progress as colored patterns, without letters.
+//!
+//! The host runs `wordler race host` and players connect with `wordler race
+//! join <addr> --name <name>`. Any player can start the race once everyone
+//! is in. The winner is whoever solves the word in the fewest guesses, with
+//! ties broken by time.
+//!
+//! Clients and server exchange one message per line. Clients send `hello
+//! <name>`, `start` and `guess <word>`; the server answers with a keyword
+//! followed by `key=value` fields, like the engine protocol:
+//!
+//! ```text
+//! welcome name=ana players=ana,bob
+//! joined name=carl
+//! left name=carl
+//! start length=<letters> max=6
+//! result word=reads pattern=01010 guesses=1
+//! progress name=bob pattern=01010 guesses=1
+//! finished name=bob solved=true guesses=3 time=12345
+//! winner name=bob guesses=3 time=12345
+//! end word=fudge
+//! error code=<code> message=<text>
+//! ```
+//!
+//! `length` is the number of letters in the race's word, `time` is in
+//! milliseconds since the start of the race, and `winner` is `winner none`
+//! when nobody found the word. After `end` the server closes
+//! the connection and the lobby opens again for a new race with a new word.
+
+use std::{
+    io::{self, BufRead, BufReader, Write},
+    net::{TcpListener, TcpStream, ToSocketAddrs},
+    sync::{
+        mpsc::{self, Sender},
+        Arc, Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use rand::{thread_rng, Rng};
+
+use crate::{word::WordList, Error, ErrorKind, Game, Guess};
+
+/// Number of guesses each player gets before they're out of the race.
+const MAX_GUESSES: usize = 6;
+
+/// How long a connection may stay silent, in the lobby or during the race,
+/// before it is closed.
+pub(crate) const IDLE_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+/// A player taking part in the race.
+struct Player {
+    /// Unique across races, so the connections of a finished race can't act
+    /// on the next one.
+    id: usize,
+    name: String,
+    game: Game,
+
+    /// Messages queued for the player's connection, `None` once they left.
+    /// Dropping it lets the connection close once its messages are sent.
+    outbox: Option<Sender<String>>,
+
+    /// Whether the player solved the word, in how many guesses and how long
+    /// after the start, once they are done.
+    finished: Option<(bool, usize, Duration)>,
+}
+
+impl Player {
+    fn connected(&self) -> bool {
+        self.outbox.is_some()
+    }
+
+    fn send(&self, message: String) {
+        if let Some(outbox) = &self.outbox {
+            let _ = outbox.send(message);
+        }
+    }
+}
+
+/// Shared state of a race, from the lobby to the final ranking.
+pub(crate) struct Lobby {
+    dict: &'static WordList,
+    seed: u64,
+    started: Option<Instant>,
+    players: Vec<Player>,
+    next_id: usize,
+}
+
+impl Lobby {
+    pub(crate) fn new(dict: &'static WordList, seed: u64) -> Self {
+        Self {
+            dict,
+            seed,
+            started: None,
+            players: vec![],
+            next_id: 0,
+        }
+    }
+
+    /// Where the player with `id` is in `players`, unless they left or their
+    /// race is over.
+    fn index(&self, id: usize) -> Option<usize> {
+        self.players.iter().position(|p| p.id == id)
+    }
+
+    /// Whether `id` is still taking part in the current race.
+    pub(crate) fn playing(&self, id: usize) -> bool {
+        self.index(id).is_some_and(|i| self.players[i].connected())
+    }
+
+    fn broadcast(&self, message: &str) {
+        for player in &self.players {
+            player.send(String::from(message));
+        }
+    }
+
+    fn broadcast_except(&self, index: usize, message: &str) {
+        for (i, player) in self.players.iter().enumerate() {
+            if i != index {
+                player.send(String::from(message));
+            }
+        }
+    }
+
+    /// Adds a player to the lobby and returns their id.
+    pub(crate) fn join(&mut self, name: &str, outbox: Sender<String>) -> Result<usize, Error> {
+        if self.started.is_some() {
+            return Err(Error::from(ErrorKind::RaceStarted));
+        }
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        {
+            return Err(Error::from(ErrorKind::InvalidArgument(String::from(
+                "names may only contain letters, digits, - and _",
+            ))));
+        }
+        if self.players.iter().any(|p| p.connected() && p.name == name) {
+            return Err(Error::from(ErrorKind::NameTaken(String::from(name))));
+        }
+
+        self.broadcast(&format!("joined name={}", name));
+        let id = self.next_id;
+        self.next_id += 1;
+        self.players.push(Player {
+            id,
+            name: String::from(name),
+            game: Game::with_seed(self.dict, self.seed),
+            outbox: Some(outbox),
+            finished: None,
+        });
+        let names: Vec<&str> = self
+            .players
+            .iter()
+            .filter(|p| p.connected())
+            .map(|p| p.name.as_str())
+            .collect();
+        self.players[self.players.len() - 1].send(format!(
+            "welcome name={} players={}",
+            name,
+            names.join(",")
+        ));
+        Ok(id)
+    }
+
+    /// Removes a player who disconnected. A race in progress goes on without
+    /// them.
+    pub(crate) fn leave(&mut self, id: usize) {
+        let Some(index) = self.index(id) else {
+            return;
+        };
+        self.players[index].outbox = None;
+        let name = self.players[index].name.clone();
+        self.broadcast(&format!("left name={}", name));
+        self.check_over();
+    }
+
+    pub(crate) fn start(&mut self) -> Result<(), Error> {
+        if self.started.is_some() {
+            return Err(Error::from(ErrorKind::RaceStarted));
+        }
+        self.started = Some(Instant::now());
+        let length = Game::with_seed(self.dict, self.seed).word.chars().count();
+        self.broadcast(&format!("start length={} max={}", length, MAX_GUESSES));
+        Ok(())
+    }
+
+    pub(crate) fn guess(&mut self, id: usize, word: &str) -> Result<(), Error> {
+        let index = self.index(id).ok_or(ErrorKind::GameOver)?;
+        let started = self.started.ok_or(ErrorKind::RaceNotStarted)?;
+        let player = &mut self.players[index];
+        if player.finished.is_some() {
+            return Err(Error::from(ErrorKind::GameOver));
+        }
+
+        let guess = player.game.guess(String::from(word))?;
+        let guesses = player.game.history.len();
+        let name = player.name.clone();
+        player.send(format!(
+            "result word={} pattern={} guesses={}",
+            player.game.language.fold(word),
+            guess.pattern(),
+            guesses
+        ));
+        self.broadcast_except(
+            index,
+            &format!(
+                "progress name={} pattern={} guesses={}",
+                name,
+                guess.pattern(),
+                guesses
+            ),
+        );
+
+        if guess.correct() || guesses >= MAX_GUESSES {
+            let time = started.elapsed();
+            self.players[index].finished = Some((guess.correct(), guesses, time));
+            self.broadcast(&format!(
+                "finished name={} solved={} guesses={} time={}",
+                name,
+                guess.correct(),
+                guesses,
+                time.as_millis()
+            ));
+            self.check_over();
+        }
+        Ok(())
+    }
+
+    /// Announces the winner once every connected player is done, then opens
+    /// the lobby for a new race.
+    fn check_over(&mut self) {
+        if self.started.is_none() {
+            return;
+        }
+        if self
+            .players
+            .iter()
+            .any(|p| p.connected() && p.finished.is_none())
+        {
+            return;
+        }
+
+        match self.winner() {
+            Some(winner) => {
+                let (_, guesses, time) = winner.finished.unwrap_or_default();
+                self.broadcast(&format!(
+                    "winner name={} guesses={} time={}",
+                    winner.name,
+                    guesses,
+                    time.as_millis()
+                ));
+            }
+            None => self.broadcast("winner none"),
+        }
+        let word = self.players.first().map(|p| p.game.word.clone());
+        self.broadcast(&format!("end word={}", word.unwrap_or_default()));
+
+        self.seed = thread_rng().gen();
+        self.started = None;
+        self.players.clear();
+    }
+
+    /// The player who solved the word in the fewest guesses, then the fastest.
+    fn winner(&self) -> Option<&Player> {
+        self.players
+            .iter()
+            .filter(|p| matches!(p.finished, Some((true, _, _))))
+            .min_by_key(|p| {
+                let (_, guesses, time) = p.finished.unwrap_or_default();
+                (guesses, time)
+            })
+    }
+}
+
+/// Hosts race lobbies on the listener, one race after the other. Each
+/// connection is handled on its own thread and closed after `timeout`
+/// without a message.
+pub(crate) fn host(
+    listener: TcpListener,
+    dict: &'static WordList,
+    timeout: Duration,
+) -> Result<(), Error> {
+    let lobby = Arc::new(Mutex::new(Lobby::new(dict, thread_rng().gen())));
+    for stream in listener.incoming() {
+        let stream = stream?;
+        let lobby = Arc::clone(&lobby);
+        thread::spawn(move || {
+            let _ = handle_connection(stream, &lobby, timeout);
+        });
+    }
+    Ok(())
+}
+
+fn handle_connection(
+    stream: TcpStream,
+    lobby: &Mutex<Lobby>,
+    timeout: Duration,
+) -> Result<(), Error> {
+    stream.set_read_timeout(Some(timeout))?;
+    stream.set_write_timeout(Some(timeout))?;
+    let (outbox, inbox) = mpsc::channel::<String>();
+    let mut writer = stream.try_clone()?;
+    thread::spawn(move || {
+        for message in inbox {
+            if writeln!(writer, "{}", message).is_err() {
+                break;
+            }
+        }
+    });
+
+    let lock = || lobby.lock().unwrap_or_else(|e| e.into_inner());
+    // A connection that times out or breaks leaves the race like one that
+    // is closed.
+    let mut id = None;
+    for line in BufReader::new(stream).lines() {
+        let Ok(line) = line else {
+            break;
+        };
+        let mut parts = line.split_whitespace();
+        let result = match (id, parts.next(), parts.next()) {
+            (_, None, _) => continue,
+            (None, Some("hello"), Some(name)) => lock()
+                .join(name, outbox.clone())
+                .map(|joined| id = Some(joined)),
+            (None, _, _) => Err(Error::from(ErrorKind::InvalidArgument(String::from(
+                "say hello <name> first",
+            )))),
+            (Some(_), Some("start"), None) => lock().start(),
+            (Some(player), Some("guess"), Some(word)) => lock().guess(player, word),
+            (Some(_), _, _) => Err(Error::from(ErrorKind::InvalidCommand)),
+        };
+        if let Err(e) = result {
+            let _ = outbox.send(format!("error code={} message={}", e.kind().code(), e));
+        }
+        if id.is_some_and(|player| !lock().playing(player)) {
+            break;
+        }
+    }
+
+    if let Some(player) = id {
+        lock().leave(player);
+    }
+    Ok(())
+}
+
+/// Joins a race as a player, reading guesses from stdin and printing the
+/// race as it unfolds, until the race ends or the player leaves.
+pub(crate) fn join<A: ToSocketAddrs>(addr: A, name: &str) -> Result<(), Error> {
+    let stream = TcpStream::connect(addr)?;
+    let mut writer = stream.try_clone()?;
+    writeln!(writer, "hello {}", name)?;
+
+    println!("Connected! Type /start once everyone has joined, then start guessing.");
+
+    // Stdin and the connection are read on their own threads, so the end of
+    // the race doesn't wait for the player to press Enter. `None` means
+    // either side is done.
+    let (lines, inbox) = mpsc::channel::<Option<String>>();
+    let done = lines.clone();
+    let printer = thread::spawn(move || {
+        let result = print_race(stream);
+        let _ = done.send(None);
+        result
+    });
+    thread::spawn(move || {
+        for line in io::stdin().lock().lines() {
+            let Ok(line) = line else {
+                break;
+            };
+            if lines.send(Some(line)).is_err() {
+                return;
+            }
+        }
+        let _ = lines.send(None);
+    });
+
+    for line in inbox {
+        let Some(line) = line else {
+            break;
+        };
+        let sent = match line.trim() {
+            "" => continue,
+            "/exit" => break,
+            "/start" => writeln!(writer, "start"),
+            guess => writeln!(writer, "guess {}", guess),
+        };
+        if sent.is_err() {
+            break;
+        }
+    }
+
+    let _ = writer.shutdown(std::net::Shutdown::Both);
+    printer.join().unwrap_or(Ok(()))
+}
+
+/// Prints the server's messages until the race ends.
+fn print_race(stream: TcpStream) -> Result<(), Error> {
+    for line in BufReader::new(stream).lines() {
+        let line = line?;
+        if let Some(text) = describe(&line) {
+            println!("{}", text);
+        }
+        if line.starts_with("end ") {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Turns a server message into text for the player.
+fn describe(line: &str) -> Option<String> {
+    let mut parts = line.split_whitespace();
+    let keyword = parts.next()?;
+    let fields: Vec<(&str, &str)> = parts.filter_map(|p| p.split_once('=')).collect();
+    let field = |key: &str| {
+        fields
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, v)| *v)
+            .unwrap_or_default()
+    };
+    let pattern = |p: &str| {
+        Guess::from_pattern(p)
+            .map(|g| g.to_string())
+            .unwrap_or_default()
+    };
+
+    Some(match keyword {
+        "welcome" => format!(
+            "Welcome {}! Players in the lobby: {}",
+            field("name"),
+            field("players")
+        ),
+        "joined" => format!("{} joined the lobby.", field("name")),
+        "left" => format!("{} left.", field("name")),
+        "start" => format!("Go! You have {} guesses.", field("max")),
+        "result" => pattern(field("pattern")),
+        "progress" => format!(
+            "{}\t{} ({})",
+            pattern(field("pattern")),
+            field("name"),
+            field("guesses")
+        ),
+        "finished" if field("solved") == "true" => format!(
+            "{} found the word in {} guesses!",
+            field("name"),
+            field("guesses")
+        ),
+        "finished" => format!("{} is out of guesses.", field("name")),
+        "winner" if line == "winner none" => String::from("Nobody found the word."),
+        "winner" => format!(
+            "🏆 {} wins with {} guesses in {:.1}s!",
+            field("name"),
+            field("guesses"),
+            field("time").parse::<f64>().unwrap_or_default() / 1000.0
+        ),
+        "end" => format!("The word was {}.", field("word")),
+        "error" => line.split_once("message=")?.1.to_string(),
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::word::fudge;
+    use std::sync::mpsc::Receiver;
+
+    fn player(lobby: &mut Lobby, name: &str) -> (usize, Receiver<String>) {
+        let (outbox, inbox) = mpsc::channel();
+        (lobby.join(name, outbox).unwrap(), inbox)
+    }
+
+    fn drain(inbox: &Receiver<String>) -> Vec<String> {
+        inbox.try_iter().collect()
+    }
+
+    #[test]
+    fn test_race() {
+        let mut lobby = Lobby::new(fudge(), 1);
+        let (ana, ana_inbox) = player(&mut lobby, "ana");
+        let (bob, bob_inbox) = player(&mut lobby, "bob");
+
+        assert_eq!(
+            drain(&ana_inbox),
+            vec!["welcome name=ana players=ana", "joined name=bob"]
+        );
+        assert_eq!(drain(&bob_inbox), vec!["welcome name=bob players=ana,bob"]);
+
+        assert!(lobby.guess(ana, "fudge").is_err());
+        lobby.start().unwrap();
+        assert!(lobby.start().is_err());
+        assert!(lobby.join("carl", mpsc::channel().0).is_err());
+
+        lobby.guess(ana, "reads").unwrap();
+        lobby.guess(bob, "fudge").unwrap();
+        lobby.guess(ana, "fudge").unwrap();
+
+        let ana_messages = drain(&ana_inbox);
+        assert_eq!(ana_messages[1], "result word=reads pattern=01010 guesses=1");
+        assert_eq!(ana_messages[2], "progress name=bob pattern=22222 guesses=1");
+        assert!(ana_messages[3].starts_with("finished name=bob solved=true guesses=1 "));
+
+        let bob_messages = drain(&bob_inbox);
+        assert!(bob_messages.contains(&String::from("progress name=ana pattern=01010 guesses=1")));
+        assert!(bob_messages
+            .iter()
+            .all(|m| !m.starts_with("progress") || !m.contains("reads")));
+        assert!(bob_messages[bob_messages.len() - 2].starts_with("winner name=bob guesses=1 "));
+        assert_eq!(bob_messages.last().unwrap(), "end word=fudge");
+    }
+
+    #[test]
+    fn test_out_of_guesses() {
+        let mut lobby = Lobby::new(fudge(), 1);
+        let (ana, ana_inbox) = player(&mut lobby, "ana");
+        let (bob, _) = player(&mut lobby, "bob");
+        lobby.start().unwrap();
+
+        for _ in 0..MAX_GUESSES {
+            lobby.guess(ana, "reads").unwrap();
+        }
+        assert!(lobby.guess(ana, "fudge").is_err());
+
+        lobby.leave(bob);
+        let messages = drain(&ana_inbox);
+        assert!(messages.contains(&String::from("winner none")));
+        assert_eq!(messages.last().unwrap(), "end word=fudge");
+    }
+
+    #[test]
+    fn test_next_race() {
+        let mut lobby = Lobby::new(fudge(), 1);
+        let (ana, _) = player(&mut lobby, "ana");
+        lobby.start().unwrap();
+        lobby.guess(ana, "fudge").unwrap();
+        assert!(!lobby.playing(ana));
+
+        // The lobby is open again, and the old race's player is gone.
+        let (bob, bob_inbox) = player(&mut lobby, "bob");
+        assert_eq!(drain(&bob_inbox), vec!["welcome name=bob players=bob"]);
+        let (ana_again, _) = player(&mut lobby, "ana");
+        assert_ne!(ana, ana_again);
+        lobby.leave(ana);
+        assert!(lobby.playing(ana_again));
+        assert!(lobby.guess(ana, "fudge").is_err());
+
+        lobby.start().unwrap();
+        lobby.guess(bob, "fudge").unwrap();
+        assert!(
+            drain(&bob_inbox).contains(&String::from("result word=fudge pattern=22222 guesses=1"))
+        );
+    }
+
+    #[test]
+    fn test_length() {
+        let dict = Box::leak(Box::new(WordList::from("fudges")));
+        let mut lobby = Lobby::new(dict, 1);
+        let (_, inbox) = player(&mut lobby, "ana");
+        lobby.start().unwrap();
+        assert!(drain(&inbox).contains(&String::from("start length=6 max=6")));
+    }
+
+    #[test]
+    fn test_names() {
+        let mut lobby = Lobby::new(fudge(), 1);
+        player(&mut lobby, "ana");
+        assert!(lobby.join("ana", mpsc::channel().0).is_err());
+        assert!(lobby.join("an a", mpsc::channel().0).is_err());
+        assert!(lobby.join("", mpsc::channel().0).is_err());
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(
+            describe("progress name=bob pattern=01210 guesses=2").unwrap(),
+            "⬛🟨🟩🟨⬛\tbob (2)"
+        );
+        assert_eq!(describe("end word=fudge").unwrap(), "The word was fudge.");
+        assert_eq!(describe("bogus"), None);
+    }
+
+    #[test]
+    fn test_tcp_race() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        thread::spawn(move || host(listener, fudge(), IDLE_TIMEOUT));
+
+        let stream = TcpStream::connect(addr).unwrap();
+        let mut writer = stream.try_clone().unwrap();
+        let mut reader = BufReader::new(stream).lines();
+        writeln!(writer, "hello ana\nstart\nguess fudge").unwrap();
+
+        let messages: Vec<String> = reader.by_ref().take(5).map(|l| l.unwrap()).collect();
+        assert_eq!(messages[0], "welcome name=ana players=ana");
+        assert_eq!(messages[1], format!("start length={} max=6", "fudge".len()));
+        assert_eq!(messages[2], "result word=fudge pattern=22222 guesses=1");
+        assert!(messages[3].starts_with("finished name=ana solved=true"));
+        assert!(messages[4].starts_with("winner name=ana"));
+        assert_eq!(reader.next().unwrap().unwrap(), "end word=fudge");
+
+        // The race is over, so the server hangs up.
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn test_idle_timeout() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        thread::spawn(move || host(listener, fudge(), Duration::from_millis(50)));
+
+        let stream = TcpStream::connect(addr).unwrap();
+        let mut writer = stream.try_clone().unwrap();
+        let mut reader = BufReader::new(stream).lines();
+        writeln!(writer, "hello ana").unwrap();
+        assert_eq!(
+            reader.next().unwrap().unwrap(),
+            "welcome name=ana players=ana"
+        );
+        assert!(reader.next().is_none());
+    }
+}
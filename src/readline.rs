@@ -0,0 +1,574 @@
+//! Line editing for the REPL prompt, for when it runs in a terminal.
+//!
+//! Lines are edited with the usual readline keys: arrows, Home and End move
+//! the cursor, Up and Down browse the guesses and commands entered before,
+//! Ctrl-A, Ctrl-E, Ctrl-K, Ctrl-U and Ctrl-W work as in a shell, and Tab
+//! completes `/` commands and, in practice games, words of the dictionary.
+//! Pressing Tab again lists the candidates when there are several. The
+//! terminal is only in raw mode while a line is being edited, so the game's
+//! output is written as usual.
+
+use std::io::{self, BufRead, BufReader, Read, Write};
+
+use crate::tui::stty;
+
+/// Candidates listed at most when completing an ambiguous prefix.
+const MAX_LISTED: usize = 30;
+
+#[derive(PartialEq, Eq, Debug)]
+enum Key {
+    Char(char),
+    Backspace,
+    Delete,
+    Left,
+    Right,
+    Home,
+    End,
+    Up,
+    Down,
+    Tab,
+    /// Ctrl-K, erases from the cursor to the end of the line.
+    KillEnd,
+    /// Ctrl-U, erases from the start of the line to the cursor.
+    KillStart,
+    /// Ctrl-W, erases the word before the cursor.
+    KillWord,
+    Enter,
+    /// Ctrl-D, ends the input on an empty line and deletes a letter
+    /// otherwise.
+    CtrlD,
+    /// Ctrl-C, or the end of the input.
+    Eof,
+}
+
+/// What the editor should do after a key press.
+#[derive(PartialEq, Eq, Debug)]
+enum Edit {
+    /// Redraw the line.
+    Redraw,
+    /// Show completion candidates, then redraw the line.
+    List(Vec<String>),
+    /// The line is done.
+    Submit(String),
+    /// No more input.
+    Eof,
+}
+
+/// State of the line being edited, and of the lines entered before.
+struct Editor {
+    line: Vec<char>,
+    cursor: usize,
+    history: Vec<String>,
+
+    /// Index in the history while browsing it, and the line that was being
+    /// edited before.
+    browsing: Option<(usize, Vec<char>)>,
+
+    /// Commands completed on Tab.
+    commands: Vec<String>,
+
+    /// Words completed on Tab, only set in practice games.
+    words: Vec<String>,
+
+    /// Whether the previous key was Tab, so a second one lists candidates.
+    tabbed: bool,
+}
+
+impl Editor {
+    fn new(commands: Vec<String>, words: Vec<String>) -> Self {
+        Self {
+            line: vec![],
+            cursor: 0,
+            history: vec![],
+            browsing: None,
+            commands,
+            words,
+            tabbed: false,
+        }
+    }
+
+    fn handle_key(&mut self, key: Key) -> Edit {
+        let tabbed = std::mem::take(&mut self.tabbed);
+        match key {
+            Key::Char(c) => {
+                self.line.insert(self.cursor, c);
+                self.cursor += 1;
+            }
+            Key::Backspace if self.cursor > 0 => {
+                self.cursor -= 1;
+                self.line.remove(self.cursor);
+            }
+            Key::Delete if self.cursor < self.line.len() => {
+                self.line.remove(self.cursor);
+            }
+            Key::Left => self.cursor = self.cursor.saturating_sub(1),
+            Key::Right => self.cursor = (self.cursor + 1).min(self.line.len()),
+            Key::Home => self.cursor = 0,
+            Key::End => self.cursor = self.line.len(),
+            Key::Up => self.browse(-1),
+            Key::Down => self.browse(1),
+            Key::Tab => return self.complete(tabbed),
+            Key::KillEnd => self.line.truncate(self.cursor),
+            Key::KillStart => {
+                self.line.drain(..self.cursor);
+                self.cursor = 0;
+            }
+            Key::KillWord => {
+                let end = self.cursor;
+                while self.cursor > 0 && self.line[self.cursor - 1] == ' ' {
+                    self.cursor -= 1;
+                }
+                while self.cursor > 0 && self.line[self.cursor - 1] != ' ' {
+                    self.cursor -= 1;
+                }
+                self.line.drain(self.cursor..end);
+            }
+            Key::Enter => return Edit::Submit(self.submit()),
+            Key::CtrlD if self.line.is_empty() => return Edit::Eof,
+            Key::CtrlD => return self.handle_key(Key::Delete),
+            Key::Eof => return Edit::Eof,
+            Key::Backspace | Key::Delete => {}
+        }
+        Edit::Redraw
+    }
+
+    /// Moves `step` lines through the history, back to the line being edited
+    /// when going past the most recent one.
+    fn browse(&mut self, step: isize) {
+        let (index, edited) = match self.browsing.take() {
+            Some(browsing) => browsing,
+            None => (self.history.len(), self.line.clone()),
+        };
+        let index = index.saturating_add_signed(step).min(self.history.len());
+        if index == self.history.len() {
+            self.line = edited;
+        } else {
+            self.line = self.history[index].chars().collect();
+            self.browsing = Some((index, edited));
+        }
+        self.cursor = self.line.len();
+    }
+
+    /// Completes the text before the cursor, or lists the candidates if it
+    /// was already completed as far as it goes.
+    fn complete(&mut self, tabbed: bool) -> Edit {
+        let prefix: String = self.line[..self.cursor].iter().collect();
+        let candidates: &[String] = if prefix.starts_with('/') {
+            &self.commands
+        } else if !prefix.is_empty() {
+            &self.words
+        } else {
+            &[]
+        };
+        let matching: Vec<&String> = candidates
+            .iter()
+            .filter(|c| c.starts_with(&prefix))
+            .collect();
+        let Some(first) = matching.first() else {
+            return Edit::Redraw;
+        };
+
+        let common = matching.iter().fold(first.as_str(), |common, c| {
+            let len = common
+                .char_indices()
+                .zip(c.chars())
+                .find(|((_, a), b)| a != b)
+                .map_or(common.len().min(c.len()), |((i, _), _)| i);
+            &common[..len]
+        });
+        if common.len() > prefix.len() {
+            let rest: Vec<char> = common[prefix.len()..].chars().collect();
+            let len = rest.len();
+            self.line.splice(self.cursor..self.cursor, rest);
+            self.cursor += len;
+            return Edit::Redraw;
+        }
+        if matching.len() == 1 || !tabbed {
+            self.tabbed = true;
+            return Edit::Redraw;
+        }
+
+        let mut listed: Vec<String> = matching
+            .iter()
+            .take(MAX_LISTED)
+            .map(|c| c.to_string())
+            .collect();
+        if matching.len() > MAX_LISTED {
+            listed.push(format!("and {} more", matching.len() - MAX_LISTED));
+        }
+        Edit::List(listed)
+    }
+
+    /// Ends the line being edited and adds it to the history.
+    fn submit(&mut self) -> String {
+        let line: String = self.line.drain(..).collect();
+        self.cursor = 0;
+        self.browsing = None;
+        if !line.trim().is_empty() && self.history.last() != Some(&line) {
+            self.history.push(line.clone());
+        }
+        line
+    }
+}
+
+/// Reads the next key press from a terminal in raw mode.
+fn read_key<R: Read>(input: &mut BufReader<R>) -> io::Result<Key> {
+    let mut byte = [0; 1];
+    loop {
+        if input.read(&mut byte)? == 0 {
+            return Ok(Key::Eof);
+        }
+        match byte[0] {
+            b'\r' | b'\n' => return Ok(Key::Enter),
+            b'\t' => return Ok(Key::Tab),
+            0x7f | 0x08 => return Ok(Key::Backspace),
+            0x01 => return Ok(Key::Home),
+            0x05 => return Ok(Key::End),
+            0x02 => return Ok(Key::Left),
+            0x06 => return Ok(Key::Right),
+            0x0b => return Ok(Key::KillEnd),
+            0x15 => return Ok(Key::KillStart),
+            0x17 => return Ok(Key::KillWord),
+            0x03 => return Ok(Key::Eof),
+            0x04 => return Ok(Key::CtrlD),
+            0x1b => {
+                if let Some(key) = read_escape(input)? {
+                    return Ok(key);
+                }
+            }
+            b @ 0x20..=0x7e => return Ok(Key::Char(b as char)),
+            // Letters such as ñ or ü arrive as multi-byte UTF-8 sequences.
+            b @ 0xc0..=0xf7 => {
+                let len = match b {
+                    0xc0..=0xdf => 2,
+                    0xe0..=0xef => 3,
+                    _ => 4,
+                };
+                let mut bytes = [b, 0, 0, 0];
+                input.read_exact(&mut bytes[1..len])?;
+                let c = std::str::from_utf8(&bytes[..len])
+                    .ok()
+                    .and_then(|s| s.chars().next());
+                if let Some(c) = c {
+                    return Ok(Key::Char(c));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Reads the rest of an escape sequence, such as `ESC [ A` for Up, and
+/// returns the key it stands for if it's one the editor knows.
+///
+/// The terminal writes a whole sequence at once, so an escape byte with
+/// nothing buffered after it is a lone Esc press, which is ignored.
+fn read_escape<R: Read>(input: &mut BufReader<R>) -> io::Result<Option<Key>> {
+    // Esc on its own, or followed by another key that is left to be read.
+    if !matches!(input.buffer().first(), Some(b'[' | b'O')) {
+        return Ok(None);
+    }
+    input.consume(1);
+
+    // Parameters, if any, come before the final byte: `ESC [ 3 ~` is Delete.
+    let mut parameter = String::new();
+    let mut byte = [0; 1];
+    while !input.buffer().is_empty() {
+        input.read_exact(&mut byte)?;
+        match byte[0] {
+            b @ b'0'..=b'9' | b @ b';' => parameter.push(b as char),
+            b'A' => return Ok(Some(Key::Up)),
+            b'B' => return Ok(Some(Key::Down)),
+            b'C' => return Ok(Some(Key::Right)),
+            b'D' => return Ok(Some(Key::Left)),
+            b'H' => return Ok(Some(Key::Home)),
+            b'F' => return Ok(Some(Key::End)),
+            b'~' => {
+                return Ok(match parameter.as_str() {
+                    "1" | "7" => Some(Key::Home),
+                    "4" | "8" => Some(Key::End),
+                    "3" => Some(Key::Delete),
+                    _ => None,
+                })
+            }
+            _ => return Ok(None),
+        }
+    }
+    Ok(None)
+}
+
+/// Lines typed in a terminal with the editor, read by the REPL like any
+/// other input.
+pub(crate) struct LineEditor<R: Read, W: Write> {
+    editor: Editor,
+    input: BufReader<R>,
+    output: W,
+
+    /// Printed before the line when it's redrawn.
+    prompt: &'static str,
+
+    /// Whether to switch the terminal to raw mode while editing, off in
+    /// tests.
+    raw: bool,
+
+    /// The last line submitted, and how much of it the REPL has read.
+    buffer: Vec<u8>,
+    consumed: usize,
+}
+
+impl<R: Read, W: Write> LineEditor<R, W> {
+    /// Edits lines read from a terminal. The prompt is the one the REPL
+    /// prints before reading a line.
+    pub(crate) fn new(
+        input: R,
+        output: W,
+        prompt: &'static str,
+        commands: &[&str],
+        words: Vec<String>,
+    ) -> Self {
+        Self {
+            editor: Editor::new(commands.iter().map(|c| c.to_string()).collect(), words),
+            input: BufReader::new(input),
+            output,
+            prompt,
+            raw: true,
+            buffer: vec![],
+            consumed: 0,
+        }
+    }
+
+    fn redraw(&mut self) -> io::Result<()> {
+        let line: String = self.editor.line.iter().collect();
+        write!(self.output, "\r\x1b[K{}{}", self.prompt, line)?;
+        let after = self.editor.line.len() - self.editor.cursor;
+        if after > 0 {
+            write!(self.output, "\x1b[{}D", after)?;
+        }
+        self.output.flush()
+    }
+
+    /// Edits a line until it's submitted, or returns `None` at the end of
+    /// the input.
+    fn read_line(&mut self) -> io::Result<Option<String>> {
+        loop {
+            match self.editor.handle_key(read_key(&mut self.input)?) {
+                Edit::Redraw => self.redraw()?,
+                Edit::List(candidates) => {
+                    write!(self.output, "\r\n{}\r\n", candidates.join("  "))?;
+                    self.redraw()?;
+                }
+                Edit::Submit(line) => {
+                    write!(self.output, "\r\n")?;
+                    return Ok(Some(line));
+                }
+                Edit::Eof => {
+                    write!(self.output, "\r\n")?;
+                    return Ok(None);
+                }
+            }
+        }
+    }
+}
+
+impl<R: Read, W: Write> Read for LineEditor<R, W> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.fill_buf()?.read(buf)?;
+        self.consume(n);
+        Ok(n)
+    }
+}
+
+impl<R: Read, W: Write> BufRead for LineEditor<R, W> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.consumed == self.buffer.len() {
+            let saved = if self.raw {
+                let saved = stty(&["-g"])?;
+                stty(&["raw", "-echo"])?;
+                Some(saved)
+            } else {
+                None
+            };
+            let line = self.read_line();
+            if let Some(saved) = saved {
+                stty(&[saved.trim()])?;
+            }
+
+            self.buffer = match line? {
+                Some(line) => format!("{}\n", line).into_bytes(),
+                None => vec![],
+            };
+            self.consumed = 0;
+        }
+        Ok(&self.buffer[self.consumed..])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.consumed = (self.consumed + amt).min(self.buffer.len());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn editor() -> Editor {
+        Editor::new(
+            vec![
+                String::from("/help"),
+                String::from("/hard"),
+                String::from("/hint"),
+            ],
+            vec![
+                String::from("fudge"),
+                String::from("lodge"),
+                String::from("lofty"),
+            ],
+        )
+    }
+
+    fn typed(editor: &mut Editor, text: &str) {
+        for c in text.chars() {
+            editor.handle_key(Key::Char(c));
+        }
+    }
+
+    #[test]
+    fn test_editing() {
+        let mut editor = editor();
+        typed(&mut editor, "fdge");
+        editor.handle_key(Key::Home);
+        editor.handle_key(Key::Right);
+        typed(&mut editor, "u");
+        editor.handle_key(Key::End);
+        editor.handle_key(Key::Backspace);
+        assert_eq!(
+            editor.handle_key(Key::Enter),
+            Edit::Submit(String::from("fudg"))
+        );
+
+        typed(&mut editor, "/new 6");
+        editor.handle_key(Key::KillWord);
+        assert_eq!(
+            editor.handle_key(Key::Enter),
+            Edit::Submit(String::from("/new "))
+        );
+
+        typed(&mut editor, "reads");
+        editor.handle_key(Key::Left);
+        editor.handle_key(Key::Left);
+        editor.handle_key(Key::KillEnd);
+        editor.handle_key(Key::Left);
+        editor.handle_key(Key::KillStart);
+        assert_eq!(
+            editor.handle_key(Key::Enter),
+            Edit::Submit(String::from("a"))
+        );
+    }
+
+    #[test]
+    fn test_history() {
+        let mut editor = editor();
+        for line in ["reads", "lodge", "lodge", ""] {
+            typed(&mut editor, line);
+            editor.handle_key(Key::Enter);
+        }
+        assert_eq!(editor.history, ["reads", "lodge"]);
+
+        typed(&mut editor, "fu");
+        editor.handle_key(Key::Up);
+        editor.handle_key(Key::Up);
+        editor.handle_key(Key::Up);
+        assert_eq!(editor.line.iter().collect::<String>(), "reads");
+        editor.handle_key(Key::Down);
+        assert_eq!(editor.line.iter().collect::<String>(), "lodge");
+        editor.handle_key(Key::Down);
+        assert_eq!(editor.line.iter().collect::<String>(), "fu");
+    }
+
+    #[test]
+    fn test_completion() {
+        let mut editor = editor();
+        typed(&mut editor, "/he");
+        editor.handle_key(Key::Tab);
+        assert_eq!(
+            editor.handle_key(Key::Enter),
+            Edit::Submit(String::from("/help"))
+        );
+
+        typed(&mut editor, "/h");
+        assert_eq!(editor.handle_key(Key::Tab), Edit::Redraw);
+        assert_eq!(
+            editor.handle_key(Key::Tab),
+            Edit::List(vec![
+                String::from("/help"),
+                String::from("/hard"),
+                String::from("/hint")
+            ])
+        );
+        editor.handle_key(Key::KillStart);
+
+        typed(&mut editor, "lo");
+        editor.handle_key(Key::Tab);
+        typed(&mut editor, "f");
+        editor.handle_key(Key::Tab);
+        assert_eq!(
+            editor.handle_key(Key::Enter),
+            Edit::Submit(String::from("lofty"))
+        );
+
+        // Nothing to complete an empty line with.
+        assert_eq!(editor.handle_key(Key::Tab), Edit::Redraw);
+        assert!(editor.line.is_empty());
+        assert_eq!(editor.handle_key(Key::CtrlD), Edit::Eof);
+    }
+
+    #[test]
+    fn test_read_key() {
+        let input = "añ\x1b[A\x1b[3~\x1bOH\x1b[F\t\x17\r\x04\x03".as_bytes();
+        let mut input = BufReader::new(input);
+        let keys = [
+            Key::Char('a'),
+            Key::Char('ñ'),
+            Key::Up,
+            Key::Delete,
+            Key::Home,
+            Key::End,
+            Key::Tab,
+            Key::KillWord,
+            Key::Enter,
+            Key::CtrlD,
+            Key::Eof,
+            Key::Eof,
+        ];
+        for key in keys {
+            assert_eq!(read_key(&mut input).unwrap(), key);
+        }
+    }
+
+    #[test]
+    fn test_read_lone_escape() {
+        // Each part arrives in its own read, like separate key presses.
+        let presses = "\x1b".as_bytes().chain("a".as_bytes());
+        let mut input = BufReader::new(presses.chain("\x1bb\x1b[D".as_bytes()));
+        assert_eq!(read_key(&mut input).unwrap(), Key::Char('a'));
+        assert_eq!(read_key(&mut input).unwrap(), Key::Char('b'));
+        assert_eq!(read_key(&mut input).unwrap(), Key::Left);
+        assert_eq!(read_key(&mut input).unwrap(), Key::Eof);
+    }
+
+    #[test]
+    fn test_line_editor() {
+        let input = "reds\x1b[D\x1b[Da\r\x1b[A\r".as_bytes();
+        let mut output = vec![];
+        let mut lines = LineEditor::new(input, &mut output, "> ", &["/help"], vec![]);
+        lines.raw = false;
+        let mut read = String::new();
+        lines.read_to_string(&mut read).unwrap();
+        assert_eq!(read, "reads\nreads\n");
+        drop(lines);
+
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.ends_with("\r\x1b[K> reads\r\n\r\n"));
+        assert!(output.contains("\r\x1b[K> reads\x1b[2D"));
+    }
+}
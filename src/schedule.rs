@@ -0,0 +1,259 @@
+//! Answer scheduling without repeats.
+//!
+//! A `Schedule` deals the words of a list in a shuffled order that only
+//! depends on its seed, so every word comes up once before any comes up
+//! again. Each pass over the list is shuffled anew. Daily puzzles walk the
+//! schedule by puzzle number; "next word" games walk their own one, skipping
+//! the words the local player has already seen.
+//!
+//! Seeded orders come from `SplitMix64` rather than `rand`, whose generators
+//! and shuffles may change between versions: a puzzle number or seed must
+//! give the same word in every build.
+
+use std::{
+    collections::HashSet,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+};
+
+use crate::{
+    word::{Word, WordList},
+    Error,
+};
+
+/// Seed of the daily puzzle schedule.
+const DAILY_SEED: u64 = 0x5eed_da11;
+
+/// Seed of the "next word" schedule, different from the daily one so next
+/// words don't give away upcoming puzzles.
+const NEXT_SEED: u64 = 0x5eed_2e47;
+
+/// The SplitMix64 generator, small and fully specified so seeded words stay
+/// the same across builds.
+pub(crate) struct SplitMix64(u64);
+
+impl SplitMix64 {
+    pub(crate) fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number below `n`, which must not be zero.
+    pub(crate) fn below(&mut self, n: usize) -> usize {
+        ((u128::from(self.next_u64()) * n as u128) >> 64) as usize
+    }
+
+    /// Shuffles `items` with the Fisher-Yates algorithm.
+    pub(crate) fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+pub(crate) struct Schedule {
+    seed: u64,
+    len: usize,
+}
+
+impl Schedule {
+    pub(crate) fn new(seed: u64, len: usize) -> Self {
+        Self { seed, len }
+    }
+
+    /// The order of the list indices in the given pass over the list.
+    pub(crate) fn pass(&self, pass: u64) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.len).collect();
+        SplitMix64::new(self.seed.wrapping_add(pass)).shuffle(&mut order);
+        order
+    }
+
+    /// The list index of the `n`th answer.
+    pub(crate) fn nth(&self, n: u64) -> Option<usize> {
+        if self.len == 0 {
+            return None;
+        }
+        let len = self.len as u64;
+        Some(self.pass(n / len)[(n % len) as usize])
+    }
+}
+
+/// The answer to a daily puzzle.
+pub(crate) fn daily(dict: &WordList, puzzle: u64) -> Option<Word> {
+    let schedule = Schedule::new(DAILY_SEED, dict.len());
+    schedule.nth(puzzle).and_then(|i| dict.get(i))
+}
+
+/// The next answer the player hasn't seen in this round, if any.
+pub(crate) fn next(dict: &WordList, seen: &Seen) -> Option<Word> {
+    Schedule::new(NEXT_SEED, dict.len())
+        .pass(seen.round)
+        .into_iter()
+        .filter_map(|i| dict.get(i))
+        .find(|w| !seen.contains(&w.to_string()))
+}
+
+/// Words the local player has already been given in the current round, kept
+/// in a file with one word per line. A blank line starts a new round, which
+/// deals the words in a new order.
+pub(crate) struct Seen {
+    path: Option<PathBuf>,
+    round: u64,
+    words: HashSet<String>,
+}
+
+impl Seen {
+    pub(crate) fn in_memory() -> Self {
+        Self {
+            path: None,
+            round: 0,
+            words: HashSet::new(),
+        }
+    }
+
+    /// Loads the words saved in `path`, which is created on the first word if
+    /// it doesn't exist yet.
+    pub(crate) fn open<P: Into<PathBuf>>(path: P) -> Result<Self, Error> {
+        let path = path.into();
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(Error::from(e)),
+        };
+
+        let mut seen = Self::in_memory();
+        for line in contents.lines() {
+            if line.is_empty() {
+                seen.round += 1;
+                seen.words.clear();
+            } else {
+                seen.words.insert(String::from(line));
+            }
+        }
+        seen.path = Some(path);
+        Ok(seen)
+    }
+
+    pub(crate) fn contains(&self, word: &str) -> bool {
+        self.words.contains(word)
+    }
+
+    /// Remembers that the player was given `word`.
+    pub(crate) fn insert(&mut self, word: &str) -> Result<(), Error> {
+        if !self.words.insert(String::from(word)) {
+            return Ok(());
+        }
+        self.append(word)
+    }
+
+    /// Forgets the words seen so far, once the player has seen them all.
+    pub(crate) fn new_round(&mut self) -> Result<(), Error> {
+        self.round += 1;
+        self.words.clear();
+        self.append("")
+    }
+
+    fn append(&self, line: &str) -> Result<(), Error> {
+        if let Some(path) = &self.path {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+            writeln!(file, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_split_mix() {
+        // Reference outputs of SplitMix64, which must never change.
+        let mut rng = SplitMix64::new(1234567);
+        assert_eq!(rng.next_u64(), 6457827717110365317);
+        assert_eq!(rng.next_u64(), 3203168211198807973);
+        assert_eq!(rng.next_u64(), 9817491932198370423);
+
+        assert!((0..100).all(|_| rng.below(3) < 3));
+        let mut order = [0, 1, 2, 3, 4];
+        SplitMix64::new(7).shuffle(&mut order);
+        assert_eq!(order, [3, 4, 2, 0, 1]);
+    }
+
+    #[test]
+    fn test_schedule() {
+        let schedule = Schedule::new(7, 10);
+        let mut first: Vec<usize> = (0..10).map(|n| schedule.nth(n).unwrap()).collect();
+        assert_ne!(first, (0..10).collect::<Vec<_>>());
+        first.sort_unstable();
+        assert_eq!(first, (0..10).collect::<Vec<_>>());
+
+        // Deterministic, and each pass is shuffled differently.
+        assert_eq!(schedule.pass(0), Schedule::new(7, 10).pass(0));
+        assert_ne!(schedule.pass(0), schedule.pass(1));
+        assert_eq!(schedule.nth(13), Some(schedule.pass(1)[3]));
+        assert_eq!(Schedule::new(7, 0).nth(0), None);
+    }
+
+    #[test]
+    fn test_daily() {
+        let dict = crate::ENGLISH.words();
+        let week: HashSet<Word> = (100..107).filter_map(|n| daily(dict, n)).collect();
+        assert_eq!(week.len(), 7);
+        assert_eq!(daily(dict, 100), daily(dict, 100));
+    }
+
+    #[test]
+    fn test_next() {
+        let dict = WordList::from("fudge\nreads\nlodge");
+        let mut seen = Seen::in_memory();
+        let mut words = vec![];
+        for _ in 0..3 {
+            let word = next(&dict, &seen).unwrap().to_string();
+            seen.insert(&word).unwrap();
+            words.push(word);
+        }
+        words.sort();
+        assert_eq!(words, ["fudge", "lodge", "reads"]);
+
+        assert_eq!(next(&dict, &seen), None);
+        seen.new_round().unwrap();
+        assert!(next(&dict, &seen).is_some());
+    }
+
+    #[test]
+    fn test_seen_file() {
+        let dir = std::env::temp_dir().join(format!("wordler-seen-{}", std::process::id()));
+        let path = dir.join("state").join("seen.txt");
+
+        let mut seen = Seen::open(&path).unwrap();
+        assert!(!seen.contains("fudge"));
+        seen.insert("fudge").unwrap();
+        seen.insert("fudge").unwrap();
+        seen.insert("reads").unwrap();
+
+        let mut reopened = Seen::open(&path).unwrap();
+        assert!(reopened.contains("fudge"));
+        assert!(reopened.contains("reads"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "fudge\nreads\n");
+
+        reopened.new_round().unwrap();
+        reopened.insert("lodge").unwrap();
+        let reopened = Seen::open(&path).unwrap();
+        assert_eq!(reopened.round, 1);
+        assert!(!reopened.contains("fudge"));
+        assert!(reopened.contains("lodge"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
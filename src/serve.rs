@@ -0,0 +1,747 @@
+//! HTTP JSON API, for web front ends that want to host games on a server.
+//!
+//! Routes:
+//!
+//! ```text
+//! GET  /daily               today's puzzle number and word length
+//! POST /games               create a game, body: {"mode": "random"|"seed"|"daily", "seed": 42, "length": 5}
+//! GET  /games/{id}          state of a game
+//! POST /games/{id}/guesses  submit a guess, body: {"word": "reads"}
+//! POST /games/{id}/giveup   end a game as a loss and reveal the word
+//! GET  /leaderboard         ranking of daily results, ?period=daily|weekly|all
+//! ```
+//!
+//! Daily games created with a `"user"` take up that user's one attempt at the
+//! puzzle as soon as they are created, and are recorded on the leaderboard
+//! once won or lost. A daily game is lost when it is given up or abandoned.
+//!
+//! Games are kept in memory and forgotten after an hour without activity. The
+//! secret word is only ever included in responses once the game is over.
+
+use std::{
+    collections::HashMap,
+    io::{BufRead, BufReader, Write},
+    net::{TcpListener, TcpStream},
+    str::FromStr,
+    sync::{Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+use rand::{thread_rng, Rng};
+
+use crate::{
+    event::letters_json,
+    json::{self, Value},
+    leaderboard::{Leaderboard, Period, Record},
+    today_puzzle,
+    word::WordList,
+    Error, ErrorKind, Game,
+};
+
+/// How long a game is kept around after its last request.
+const GAME_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// Largest request body accepted, guesses and game options are tiny.
+const MAX_BODY: usize = 16 * 1024;
+
+/// Games in progress, keyed by their id.
+struct Store {
+    games: HashMap<String, (Game, Instant)>,
+    ttl: Duration,
+
+    /// Users playing daily games, keyed by game id.
+    users: HashMap<String, String>,
+    leaderboard: Leaderboard,
+}
+
+impl Store {
+    fn new(ttl: Duration, leaderboard: Leaderboard) -> Self {
+        Self {
+            games: HashMap::new(),
+            ttl,
+            users: HashMap::new(),
+            leaderboard,
+        }
+    }
+
+    /// Forgets games that haven't been touched within the ttl. Daily games
+    /// still being played count as lost.
+    fn expire(&mut self, now: Instant) -> Result<(), Error> {
+        let ttl = self.ttl;
+        let expired: Vec<String> = self
+            .games
+            .iter()
+            .filter(|(_, (_, last_access))| now.duration_since(*last_access) >= ttl)
+            .map(|(id, _)| id.clone())
+            .collect();
+
+        let mut result = Ok(());
+        for id in expired {
+            if self.games[&id].0.keep_going {
+                result = result.and(self.record(&id));
+            }
+            self.games.remove(&id);
+            self.users.remove(&id);
+        }
+        result
+    }
+
+    /// Saves the result of a finished daily game on the leaderboard, if it
+    /// was played by a user.
+    fn record(&mut self, id: &str) -> Result<(), Error> {
+        match (self.games.get(id), self.users.get(id)) {
+            (Some((game, _)), Some(user)) => match game.puzzle {
+                Some(puzzle) => self.leaderboard.record(Record {
+                    user: user.clone(),
+                    puzzle,
+                    guesses: game.history.len(),
+                    won: game.won(),
+                }),
+                None => Ok(()),
+            },
+            _ => Ok(()),
+        }
+    }
+
+    fn insert(&mut self, game: Game) -> String {
+        let id = loop {
+            let id = format!("{:016x}", thread_rng().gen::<u64>());
+            if !self.games.contains_key(&id) {
+                break id;
+            }
+        };
+        self.games.insert(id.clone(), (game, Instant::now()));
+        id
+    }
+
+    fn get_mut(&mut self, id: &str) -> Result<&mut Game, Error> {
+        match self.games.get_mut(id) {
+            Some((game, last_access)) => {
+                *last_access = Instant::now();
+                Ok(game)
+            }
+            None => Err(Error::from(ErrorKind::UnknownGame(String::from(id)))),
+        }
+    }
+}
+
+struct Request {
+    method: String,
+    path: String,
+    body: String,
+}
+
+struct Response {
+    status: u16,
+    body: String,
+}
+
+impl Response {
+    fn ok(status: u16, body: String) -> Self {
+        Self { status, body }
+    }
+
+    fn error(status: u16, code: &str, message: &str) -> Self {
+        let error = json::Object::new()
+            .str("code", code)
+            .str("message", message)
+            .finish();
+        Self {
+            status,
+            body: json::Object::new().raw("error", error).finish(),
+        }
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            201 => "Created",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            409 => "Conflict",
+            413 => "Payload Too Large",
+            _ => "Internal Server Error",
+        }
+    }
+}
+
+impl From<Error> for Response {
+    fn from(e: Error) -> Self {
+        let status = match e.kind() {
+            ErrorKind::UnknownGame(_) => 404,
+            ErrorKind::GameOver | ErrorKind::AlreadyPlayed(_) => 409,
+            ErrorKind::IoError(_) => 500,
+            _ => 400,
+        };
+        Response::error(status, e.kind().code(), &e.to_string())
+    }
+}
+
+/// Serves the API on the listener until the process is stopped. Each
+/// connection is handled on its own thread.
+pub(crate) fn serve(
+    listener: TcpListener,
+    dict: &'static WordList,
+    leaderboard: Leaderboard,
+) -> Result<(), Error> {
+    let store = Arc::new(Mutex::new(Store::new(GAME_TTL, leaderboard)));
+    for stream in listener.incoming() {
+        let stream = stream?;
+        let store = Arc::clone(&store);
+        thread::spawn(move || {
+            // A client hanging up mid-request only affects its own connection.
+            let _ = handle_connection(stream, &store, dict);
+        });
+    }
+    Ok(())
+}
+
+fn handle_connection(
+    stream: TcpStream,
+    store: &Mutex<Store>,
+    dict: &WordList,
+) -> Result<(), Error> {
+    stream.set_read_timeout(Some(Duration::from_secs(10)))?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let response = match read_request(&mut reader)? {
+        Ok(request) => {
+            let mut store = store.lock().unwrap_or_else(|e| e.into_inner());
+            if let Err(e) = store.expire(Instant::now()) {
+                eprintln!("{}", e);
+            }
+            handle(&mut store, dict, &request)
+        }
+        Err(response) => response,
+    };
+
+    let mut stream = stream;
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        response.reason(),
+        response.body.len(),
+        response.body
+    )?;
+    stream.flush()?;
+    Ok(())
+}
+
+/// Reads a request from the connection. Malformed requests are answered with
+/// an error response instead.
+fn read_request<R: BufRead>(reader: &mut R) -> Result<Result<Request, Response>, Error> {
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let mut parts = line.split_whitespace();
+    let (method, path) = match (parts.next(), parts.next()) {
+        (Some(method), Some(path)) => (method.to_string(), path.to_string()),
+        _ => {
+            return Ok(Err(Response::error(
+                400,
+                "bad_request",
+                "malformed request line.",
+            )))
+        }
+    };
+
+    let mut content_length = 0;
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 || line.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                match value.trim().parse() {
+                    Ok(length) => content_length = length,
+                    Err(_) => {
+                        return Ok(Err(Response::error(
+                            400,
+                            "bad_request",
+                            "malformed content length.",
+                        )))
+                    }
+                }
+            }
+        }
+    }
+    if content_length > MAX_BODY {
+        return Ok(Err(Response::error(
+            413,
+            "body_too_large",
+            "request body too large.",
+        )));
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+    Ok(Ok(Request {
+        method,
+        path,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    }))
+}
+
+fn handle(store: &mut Store, dict: &WordList, request: &Request) -> Response {
+    let (path, query) = request
+        .path
+        .split_once('?')
+        .unwrap_or((request.path.as_str(), ""));
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+    let result = match (request.method.as_str(), segments.as_slice()) {
+        ("GET", ["daily"]) => daily(dict),
+        ("GET", ["leaderboard"]) => leaderboard(store, query),
+        ("POST", ["games"]) => create(store, dict, &request.body),
+        ("GET", ["games", id]) => store
+            .get_mut(id)
+            .map(|game| Response::ok(200, state(id, game))),
+        ("POST", ["games", id, "guesses"]) => guess(store, id, &request.body),
+        ("POST", ["games", id, "giveup"]) => give_up(store, id),
+        (_, ["daily"])
+        | (_, ["leaderboard"])
+        | (_, ["games"])
+        | (_, ["games", _])
+        | (_, ["games", _, "guesses"])
+        | (_, ["games", _, "giveup"]) => {
+            return Response::error(405, "method_not_allowed", "method not allowed.")
+        }
+        _ => return Response::error(404, "not_found", "no such route."),
+    };
+    result.unwrap_or_else(Response::from)
+}
+
+fn daily(dict: &WordList) -> Result<Response, Error> {
+    let game = Game::daily(dict)?;
+    let body = json::Object::new()
+        .num("puzzle", today_puzzle())
+        .num("length", game.word.chars().count())
+        .finish();
+    Ok(Response::ok(200, body))
+}
+
+fn leaderboard(store: &Store, query: &str) -> Result<Response, Error> {
+    let period = query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(key, _)| *key == "period")
+        .map(|(_, value)| value);
+    let (period, name) = match period {
+        None => (Period::Daily, "daily"),
+        Some(name) => (Period::from_str(name)?, name),
+    };
+
+    let puzzle = today_puzzle();
+    let standings = store.leaderboard.standings(period, puzzle);
+    let standings = standings.iter().map(|s| {
+        let average = s
+            .average()
+            .map(|a| format!("{:.2}", a))
+            .unwrap_or_else(|| String::from("null"));
+        json::Object::new()
+            .str("user", &s.user)
+            .num("played", s.played)
+            .num("won", s.won)
+            .raw("average", average)
+            .num("streak", s.streak)
+            .num("max_streak", s.max_streak)
+            .finish()
+    });
+    let body = json::Object::new()
+        .str("period", name)
+        .num("puzzle", puzzle)
+        .raw("standings", json::array(standings))
+        .finish();
+    Ok(Response::ok(200, body))
+}
+
+/// Parses a request body, treating an empty body as an empty object.
+fn body(body: &str) -> Result<Value, Error> {
+    if body.trim().is_empty() {
+        return Ok(Value::Object(vec![]));
+    }
+    Value::parse(body).ok_or_else(|| {
+        Error::from(ErrorKind::InvalidArgument(String::from(
+            "request body is not valid JSON",
+        )))
+    })
+}
+
+fn create(store: &mut Store, dict: &WordList, request: &str) -> Result<Response, Error> {
+    let options = body(request)?;
+    let words;
+    let dict = match options.get("length") {
+        None => dict,
+        Some(length) => {
+            let length = length.as_u64().ok_or_else(|| {
+                Error::from(ErrorKind::InvalidArgument(String::from(
+                    "length must be a number",
+                )))
+            })? as usize;
+            words = dict.filter(|w| w.letters().count() == length);
+            if words.is_empty() {
+                return Err(Error::from(ErrorKind::NoWordsOfLength(length)));
+            }
+            &words
+        }
+    };
+
+    let mode = options
+        .get("mode")
+        .and_then(Value::as_str)
+        .unwrap_or("random");
+    let game = match mode {
+        "random" => Game::from(dict),
+        "daily" => Game::daily(dict)?,
+        "seed" => {
+            let seed = options.get("seed").and_then(Value::as_u64).ok_or_else(|| {
+                Error::from(ErrorKind::InvalidArgument(String::from(
+                    "seed mode requires a numeric seed",
+                )))
+            })?;
+            Game::with_seed(dict, seed)
+        }
+        other => {
+            return Err(Error::from(ErrorKind::InvalidArgument(format!(
+                "unknown mode {}, expected random, seed or daily",
+                other
+            ))))
+        }
+    };
+
+    let user = options.get("user").and_then(Value::as_str);
+    if let (Some(user), Some(puzzle)) = (user, game.puzzle) {
+        store.leaderboard.start(user, puzzle)?;
+    }
+
+    let id = store.insert(game);
+    if let (Some(user), "daily") = (user, mode) {
+        store.users.insert(id.clone(), String::from(user));
+    }
+    let game = store.get_mut(&id)?;
+    Ok(Response::ok(201, state(&id, game)))
+}
+
+fn guess(store: &mut Store, id: &str, request: &str) -> Result<Response, Error> {
+    let word = body(request)?
+        .get("word")
+        .and_then(Value::as_str)
+        .map(String::from)
+        .ok_or_else(|| {
+            Error::from(ErrorKind::InvalidArgument(String::from(
+                "guess requires a word",
+            )))
+        })?;
+
+    let game = store.get_mut(id)?;
+    if !game.keep_going {
+        return Err(Error::from(ErrorKind::GameOver));
+    }
+    if game.guess(word)?.correct() {
+        game.keep_going = false;
+        store.record(id)?;
+    }
+
+    let game = store.get_mut(id)?;
+    Ok(Response::ok(200, state(id, game)))
+}
+
+fn give_up(store: &mut Store, id: &str) -> Result<Response, Error> {
+    let game = store.get_mut(id)?;
+    if !game.keep_going {
+        return Err(Error::from(ErrorKind::GameOver));
+    }
+    game.keep_going = false;
+    store.record(id)?;
+
+    let game = store.get_mut(id)?;
+    Ok(Response::ok(200, state(id, game)))
+}
+
+/// Describes a game without giving away the word while it is still playing.
+fn state(id: &str, game: &Game) -> String {
+    let guesses = game.history.iter().map(|(word, guess)| {
+        json::Object::new()
+            .str("word", word)
+            .raw("letters", letters_json(word, guess))
+            .bool("correct", guess.correct())
+            .finish()
+    });
+    let state = json::Object::new()
+        .str("id", id)
+        .num("length", game.word.chars().count())
+        .str(
+            "status",
+            match (game.keep_going, game.won()) {
+                (true, _) => "playing",
+                (false, true) => "won",
+                (false, false) => "lost",
+            },
+        )
+        .opt_num("puzzle", game.puzzle)
+        .raw("guesses", json::array(guesses));
+    if game.keep_going {
+        state.finish()
+    } else {
+        state.str("word", &game.word).finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::word::fudge;
+    use std::io::Read;
+
+    fn request(store: &mut Store, method: &str, path: &str, body: &str) -> (u16, Value) {
+        request_in(store, fudge(), method, path, body)
+    }
+
+    fn request_in(
+        store: &mut Store,
+        dict: &WordList,
+        method: &str,
+        path: &str,
+        body: &str,
+    ) -> (u16, Value) {
+        let response = handle(
+            store,
+            dict,
+            &Request {
+                method: String::from(method),
+                path: String::from(path),
+                body: String::from(body),
+            },
+        );
+        (response.status, Value::parse(&response.body).unwrap())
+    }
+
+    #[test]
+    fn test_game_lifecycle() {
+        let mut store = Store::new(GAME_TTL, Leaderboard::in_memory());
+
+        let (status, game) = request(
+            &mut store,
+            "POST",
+            "/games",
+            r#"{"mode": "seed", "seed": 1}"#,
+        );
+        assert_eq!(status, 201);
+        assert_eq!(game.get("status").and_then(Value::as_str), Some("playing"));
+        assert_eq!(game.get("word"), None);
+        let id = game.get("id").and_then(Value::as_str).unwrap().to_string();
+
+        let (status, game) = request(
+            &mut store,
+            "POST",
+            &format!("/games/{}/guesses", id),
+            r#"{"word": "reads"}"#,
+        );
+        assert_eq!(status, 200);
+        assert_eq!(game.get("word"), None);
+
+        let (status, _) = request(
+            &mut store,
+            "POST",
+            &format!("/games/{}/guesses", id),
+            r#"{"word": "fudge"}"#,
+        );
+        assert_eq!(status, 200);
+
+        let (status, game) = request(&mut store, "GET", &format!("/games/{}", id), "");
+        assert_eq!(status, 200);
+        assert_eq!(game.get("status").and_then(Value::as_str), Some("won"));
+        assert_eq!(game.get("word").and_then(Value::as_str), Some("fudge"));
+
+        let (status, error) = request(
+            &mut store,
+            "POST",
+            &format!("/games/{}/guesses", id),
+            r#"{"word": "fudge"}"#,
+        );
+        assert_eq!(status, 409);
+        assert_eq!(
+            error
+                .get("error")
+                .and_then(|e| e.get("code"))
+                .and_then(Value::as_str),
+            Some("game_over")
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut store = Store::new(GAME_TTL, Leaderboard::in_memory());
+
+        assert_eq!(request(&mut store, "GET", "/games/nope", "").0, 404);
+        assert_eq!(request(&mut store, "GET", "/nope", "").0, 404);
+        assert_eq!(request(&mut store, "DELETE", "/games", "").0, 405);
+        assert_eq!(request(&mut store, "POST", "/games", "{").0, 400);
+        let nested = "[".repeat(MAX_BODY);
+        assert_eq!(request(&mut store, "POST", "/games", &nested).0, 400);
+        assert_eq!(
+            request(&mut store, "POST", "/games", r#"{"length": 6}"#).0,
+            400
+        );
+        assert_eq!(
+            request(&mut store, "POST", "/games", r#"{"mode": "seed"}"#).0,
+            400
+        );
+
+        let (_, game) = request(&mut store, "POST", "/games", "");
+        let id = game.get("id").and_then(Value::as_str).unwrap().to_string();
+        let (status, error) = request(
+            &mut store,
+            "POST",
+            &format!("/games/{}/guesses", id),
+            r#"{"word": "lol"}"#,
+        );
+        assert_eq!(status, 400);
+        assert_eq!(
+            error
+                .get("error")
+                .and_then(|e| e.get("code"))
+                .and_then(Value::as_str),
+            Some("guess_too_short")
+        );
+    }
+
+    #[test]
+    fn test_length() {
+        let mut store = Store::new(GAME_TTL, Leaderboard::in_memory());
+        let dict = WordList::from("fudge\nreads\nfudges");
+        let six = WordList::from("fudges\nbreads");
+        let length = |game: &Value| game.get("length").and_then(Value::as_u64);
+
+        let (status, game) = request_in(&mut store, &dict, "POST", "/games", r#"{"length": 6}"#);
+        assert_eq!(status, 201);
+        assert_eq!(length(&game), Some(6));
+
+        let (status, error) = request_in(&mut store, &dict, "POST", "/games", r#"{"length": 7}"#);
+        assert_eq!(status, 400);
+        assert_eq!(
+            error
+                .get("error")
+                .and_then(|e| e.get("code"))
+                .and_then(Value::as_str),
+            Some("no_words_of_length")
+        );
+
+        let (status, daily) = request_in(&mut store, &six, "GET", "/daily", "");
+        assert_eq!(status, 200);
+        assert_eq!(length(&daily), Some(6));
+        let (_, game) = request_in(&mut store, &six, "POST", "/games", r#"{"mode": "daily"}"#);
+        assert_eq!(length(&game), Some(6));
+    }
+
+    #[test]
+    fn test_read_request() {
+        let mut input = "POST /games HTTP/1.1\r\nContent-Length: 2x\r\n\r\n{}".as_bytes();
+        let request = read_request(&mut input).unwrap();
+        assert!(matches!(request, Err(Response { status: 400, .. })));
+
+        let mut input = "POST /games HTTP/1.1\r\nContent-Length: 2\r\n\r\n{}".as_bytes();
+        assert_eq!(read_request(&mut input).unwrap().ok().unwrap().body, "{}");
+    }
+
+    #[test]
+    fn test_daily_leaderboard() {
+        let mut store = Store::new(GAME_TTL, Leaderboard::in_memory());
+        let create = r#"{"mode": "daily", "user": "ana"}"#;
+
+        let (status, game) = request(&mut store, "POST", "/games", create);
+        assert_eq!(status, 201);
+        let id = game.get("id").and_then(Value::as_str).unwrap().to_string();
+
+        // The attempt is taken as soon as the game is created.
+        assert_eq!(request(&mut store, "POST", "/games", create).0, 409);
+
+        let guesses = |id: &str| format!("/games/{}/guesses", id);
+        request(&mut store, "POST", &guesses(&id), r#"{"word": "reads"}"#);
+        request(&mut store, "POST", &guesses(&id), r#"{"word": "fudge"}"#);
+        assert_eq!(request(&mut store, "POST", "/games", create).0, 409);
+
+        // Giving up counts as a loss.
+        let create = r#"{"mode": "daily", "user": "bob"}"#;
+        let (_, game) = request(&mut store, "POST", "/games", create);
+        let id = game.get("id").and_then(Value::as_str).unwrap().to_string();
+        let (status, game) = request(&mut store, "POST", &format!("/games/{}/giveup", id), "");
+        assert_eq!(status, 200);
+        assert_eq!(game.get("status").and_then(Value::as_str), Some("lost"));
+        assert_eq!(game.get("word").and_then(Value::as_str), Some("fudge"));
+        assert_eq!(
+            request(&mut store, "POST", &format!("/games/{}/giveup", id), "").0,
+            409
+        );
+
+        let (status, board) = request(&mut store, "GET", "/leaderboard?period=weekly", "");
+        assert_eq!(status, 200);
+        assert_eq!(board.get("period").and_then(Value::as_str), Some("weekly"));
+        let standings = match board.get("standings") {
+            Some(Value::Array(standings)) => standings,
+            other => panic!("unexpected standings {:?}", other),
+        };
+        assert_eq!(standings.len(), 2);
+        assert_eq!(
+            standings[0].get("user").and_then(Value::as_str),
+            Some("ana")
+        );
+        assert_eq!(standings[0].get("average"), Some(&Value::Number(2.0)));
+        assert_eq!(standings[1].get("won"), Some(&Value::Number(0.0)));
+
+        assert_eq!(
+            request(&mut store, "GET", "/leaderboard?period=yearly", "").0,
+            400
+        );
+        assert_eq!(request(&mut store, "POST", "/leaderboard", "").0, 405);
+    }
+
+    #[test]
+    fn test_expiry() {
+        let mut store = Store::new(Duration::from_secs(60), Leaderboard::in_memory());
+        let id = store.insert(Game::new(String::from("fudge")));
+        let mut daily = Game::new(String::from("fudge"));
+        daily.puzzle = Some(3);
+        let daily = store.insert(daily);
+        store.leaderboard.start("ana", 3).unwrap();
+        store.users.insert(daily.clone(), String::from("ana"));
+
+        store.expire(Instant::now()).unwrap();
+        assert!(store.get_mut(&id).is_ok());
+
+        store
+            .expire(Instant::now() + Duration::from_secs(61))
+            .unwrap();
+        assert!(store.get_mut(&id).is_err());
+
+        // An abandoned daily game is lost.
+        let standings = store.leaderboard.standings(Period::Daily, 3);
+        assert_eq!((standings[0].played, standings[0].won), (1, 0));
+    }
+
+    #[test]
+    fn test_http_client() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        thread::spawn(move || serve(listener, fudge(), Leaderboard::in_memory()));
+
+        let mut stream = TcpStream::connect(addr).unwrap();
+        let body = r#"{"mode": "random"}"#;
+        write!(
+            stream,
+            "POST /games HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{}",
+            body.len(),
+            body
+        )
+        .unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 201 Created\r\n"));
+        let (_, body) = response.split_once("\r\n\r\n").unwrap();
+        let game = Value::parse(body).unwrap();
+        assert_eq!(game.get("length").and_then(Value::as_u64), Some(5));
+        assert_eq!(game.get("word"), None);
+    }
+}
@@ -0,0 +1,119 @@
+//! Plain TCP server running an independent REPL per connection, so anyone on
+//! the network can play with `nc host 7777` or `telnet host 7777`.
+
+use std::{
+    io::{self, BufReader, Write},
+    net::{TcpListener, TcpStream},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+
+use crate::{
+    event::{Format, Writer},
+    word::WordList,
+    Error, ErrorKind, Game,
+};
+
+/// How long a session may stay silent before it is closed.
+pub(crate) const IDLE_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+/// Maximum number of games played at the same time.
+const MAX_SESSIONS: usize = 64;
+
+/// Accepts connections until the process is stopped, each playing its own
+/// game on its own thread.
+pub(crate) fn listen(
+    listener: TcpListener,
+    dict: &'static WordList,
+    timeout: Duration,
+) -> Result<(), Error> {
+    let sessions = Arc::new(AtomicUsize::new(0));
+    for stream in listener.incoming() {
+        let mut stream = stream?;
+        if sessions.load(Ordering::SeqCst) >= MAX_SESSIONS {
+            let _ = writeln!(stream, "Too many players right now, try again later.");
+            continue;
+        }
+
+        sessions.fetch_add(1, Ordering::SeqCst);
+        let sessions = Arc::clone(&sessions);
+        thread::spawn(move || {
+            // Errors only end the session they happened in.
+            let _ = session(stream, dict, timeout);
+            sessions.fetch_sub(1, Ordering::SeqCst);
+        });
+    }
+    Ok(())
+}
+
+fn session(stream: TcpStream, dict: &WordList, timeout: Duration) -> Result<(), Error> {
+    stream.set_read_timeout(Some(timeout))?;
+    stream.set_write_timeout(Some(timeout))?;
+    let reader = BufReader::new(stream.try_clone()?);
+    let mut writer = stream;
+
+    let mut sink = Writer::new(&mut writer, Format::Text);
+    match Game::from(dict).repl(dict, reader, &mut sink) {
+        Err(Error {
+            kind: ErrorKind::IoError(e),
+        }) if matches!(
+            e.kind(),
+            io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+        ) =>
+        {
+            writeln!(writer, "\nIdle for too long, bye!")?;
+            Ok(())
+        }
+        result => result,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::word::fudge;
+    use std::io::Read;
+
+    fn server(timeout: Duration) -> std::net::SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        thread::spawn(move || listen(listener, fudge(), timeout));
+        addr
+    }
+
+    #[test]
+    fn test_concurrent_sessions() {
+        let addr = server(IDLE_TIMEOUT);
+        let mut first = TcpStream::connect(addr).unwrap();
+        let mut second = TcpStream::connect(addr).unwrap();
+
+        second.write_all(b"reads\r\nfudge\r\n").unwrap();
+        first.write_all(b"fudge\n").unwrap();
+
+        let mut output = String::new();
+        first.read_to_string(&mut output).unwrap();
+        assert!(output.starts_with("Welcome to Wordler!"));
+        assert!(output.contains("> 🟩🟩🟩🟩🟩\nCongrats! 🎉\n"));
+        assert!(
+            output.ends_with("1. FUDGE 1 → 1 word, 0.0 bits (0.0 expected), as the solver would\n")
+        );
+
+        let mut output = String::new();
+        second.read_to_string(&mut output).unwrap();
+        assert!(output.contains("> ⬛🟨⬛🟨⬛\n> 🟩🟩🟩🟩🟩\nCongrats! 🎉\n"));
+    }
+
+    #[test]
+    fn test_idle_timeout() {
+        let addr = server(Duration::from_millis(50));
+        let mut idle = TcpStream::connect(addr).unwrap();
+
+        let mut output = String::new();
+        idle.read_to_string(&mut output).unwrap();
+        assert!(output.ends_with("Idle for too long, bye!\n"));
+    }
+}
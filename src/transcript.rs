@@ -0,0 +1,469 @@
+//! Recording of finished games, for sharing them and reproducing scoring bugs.
+//!
+//! Transcripts are saved as JSON with the way the word was chosen, the
+//! language, a hash of the word list, every guess with its feedback and
+//! timing, and the outcome. Accent folding is part of the language profile
+//! rather than a setting of its own, so the language code is all a replay
+//! needs to read the guesses the way they were played.
+//! `wordler replay <file>` steps through a transcript in the terminal,
+//! re-scoring each guess with the current code so differences stand out, and
+//! `--markdown` exports it for sharing.
+
+use std::{
+    fs,
+    io::{self, BufRead, Write},
+    path::Path,
+    time::{Instant, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    event::{Event, Sink},
+    json::{self, Value},
+    lang::{Language, ENGLISH},
+    word::WordList,
+    Error, ErrorKind, Game, Guess, Mode,
+};
+
+/// Version of the transcript format, bumped on incompatible changes.
+const VERSION: u64 = 1;
+
+/// Hashes a word list with 64-bit FNV-1a, which unlike the standard library
+/// hasher is stable across releases and platforms. Words are hashed one per
+/// line, so the hash matches the generated text of the list.
+pub(crate) fn dict_hash(dict: &WordList) -> String {
+    let words: Vec<String> = dict.iter().map(|w| w.to_string()).collect();
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in words.join("\n").bytes() {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    format!("{:016x}", hash)
+}
+
+/// A single guess of a recorded game.
+#[derive(PartialEq, Eq, Debug)]
+pub(crate) struct Step {
+    pub(crate) word: String,
+    pub(crate) pattern: String,
+
+    /// Milliseconds since the start of the game.
+    pub(crate) at_ms: u64,
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub(crate) struct Transcript {
+    pub(crate) mode: Mode,
+    pub(crate) seed: Option<u64>,
+    pub(crate) puzzle: Option<u64>,
+    pub(crate) language: &'static Language,
+    pub(crate) dict_hash: String,
+    pub(crate) word: String,
+
+    /// Start of the game, in seconds since the Unix epoch.
+    pub(crate) started: u64,
+    pub(crate) steps: Vec<Step>,
+
+    /// Whether the word was found, `None` while the game is unfinished.
+    pub(crate) won: Option<bool>,
+}
+
+impl Transcript {
+    /// Starts an empty transcript for a game that is about to be played.
+    pub(crate) fn new(game: &Game, dict: &WordList) -> Self {
+        Self {
+            mode: game.mode,
+            seed: game.seed,
+            puzzle: game.puzzle,
+            language: game.language,
+            dict_hash: dict_hash(dict),
+            word: game.word.clone(),
+            started: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default(),
+            steps: vec![],
+            won: None,
+        }
+    }
+
+    pub(crate) fn to_json(&self) -> String {
+        let steps = self.steps.iter().map(|step| {
+            json::Object::new()
+                .str("word", &step.word)
+                .str("pattern", &step.pattern)
+                .num("at_ms", step.at_ms)
+                .finish()
+        });
+        let outcome = match self.won {
+            Some(true) => "won",
+            Some(false) => "lost",
+            None => "unfinished",
+        };
+        json::Object::new()
+            .num("version", VERSION)
+            .str("mode", self.mode.name())
+            .opt_num("seed", self.seed)
+            .opt_num("puzzle", self.puzzle)
+            .str("language", self.language.code)
+            .str("dict_hash", &self.dict_hash)
+            .str("word", &self.word)
+            .num("started", self.started)
+            .raw("guesses", json::array(steps))
+            .str("outcome", outcome)
+            .finish()
+    }
+
+    pub(crate) fn from_json(input: &str) -> Result<Self, Error> {
+        let invalid = |what: &str| Error::from(ErrorKind::InvalidTranscript(String::from(what)));
+        let value = Value::parse(input).ok_or_else(|| invalid("not valid JSON"))?;
+        let str_field = |key: &str| {
+            value
+                .get(key)
+                .and_then(Value::as_str)
+                .map(String::from)
+                .ok_or_else(|| invalid(&format!("missing {}", key)))
+        };
+
+        if value.get("version").and_then(Value::as_u64) != Some(VERSION) {
+            return Err(invalid("unsupported version"));
+        }
+        let mode = Mode::from_name(&str_field("mode")?).ok_or_else(|| invalid("unknown mode"))?;
+        // Transcripts recorded before languages were saved are English.
+        let language = match value.get("language").and_then(Value::as_str) {
+            Some(code) => code.parse().map_err(|_| invalid("unknown language"))?,
+            None => &ENGLISH,
+        };
+        let won = match str_field("outcome")?.as_str() {
+            "won" => Some(true),
+            "lost" => Some(false),
+            "unfinished" => None,
+            _ => return Err(invalid("unknown outcome")),
+        };
+
+        let mut steps = vec![];
+        let guesses = match value.get("guesses") {
+            Some(Value::Array(guesses)) => guesses,
+            _ => return Err(invalid("missing guesses")),
+        };
+        for guess in guesses {
+            let field = |key: &str| guess.get(key).and_then(Value::as_str).map(String::from);
+            match (
+                field("word"),
+                field("pattern"),
+                guess.get("at_ms").and_then(Value::as_u64),
+            ) {
+                (Some(word), Some(pattern), Some(at_ms)) => steps.push(Step {
+                    word,
+                    pattern,
+                    at_ms,
+                }),
+                _ => return Err(invalid("malformed guess")),
+            }
+        }
+
+        Ok(Self {
+            mode,
+            seed: value.get("seed").and_then(Value::as_u64),
+            puzzle: value.get("puzzle").and_then(Value::as_u64),
+            language,
+            dict_hash: str_field("dict_hash")?,
+            word: str_field("word")?,
+            started: value
+                .get("started")
+                .and_then(Value::as_u64)
+                .unwrap_or_default(),
+            steps,
+            won,
+        })
+    }
+
+    pub(crate) fn load<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        Self::from_json(&fs::read_to_string(path)?)
+    }
+
+    pub(crate) fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        fs::write(path, self.to_json() + "\n")?;
+        Ok(())
+    }
+
+    fn title(&self) -> String {
+        match (self.mode, self.puzzle, self.seed) {
+            (Mode::Daily, Some(puzzle), _) => format!("Wordler #{}", puzzle),
+            (Mode::Puzzle, Some(puzzle), _) => format!("Wordler #{} (practice)", puzzle),
+            (Mode::Word, _, _) => String::from("Wordler practice game"),
+            (_, _, Some(seed)) => format!("Wordler game (seed {})", seed),
+            (mode, _, None) => format!("Wordler game ({})", mode.name()),
+        }
+    }
+
+    fn outcome(&self) -> String {
+        let time = format_ms(self.steps.last().map(|s| s.at_ms).unwrap_or_default());
+        match self.won {
+            Some(true) => format!("won in {} guesses ({})", self.steps.len(), time),
+            Some(false) => format!("lost after {} guesses ({})", self.steps.len(), time),
+            None => format!("unfinished after {} guesses", self.steps.len()),
+        }
+    }
+
+    /// Exports the transcript as a Markdown document.
+    pub(crate) fn to_markdown(&self) -> String {
+        let mut md = format!(
+            "# {}\n\n- Word: `{}`\n- Outcome: {}\n- Word list: `{}`\n\n| # | Guess | Result | Time |\n|---|-------|--------|------|\n",
+            self.title(),
+            self.word,
+            self.outcome(),
+            self.dict_hash
+        );
+        for (i, step) in self.steps.iter().enumerate() {
+            md.push_str(&format!(
+                "| {} | `{}` | {} | {} |\n",
+                i + 1,
+                step.word.to_uppercase(),
+                render_pattern(&step.pattern),
+                format_ms(step.at_ms)
+            ));
+        }
+        md
+    }
+}
+
+fn render_pattern(pattern: &str) -> String {
+    Guess::from_pattern(pattern)
+        .map(|g| g.to_string())
+        .unwrap_or_else(|| String::from(pattern))
+}
+
+fn format_ms(ms: u64) -> String {
+    let secs = ms / 1000;
+    format!("{}:{:02}", secs / 60, secs % 60)
+}
+
+/// Passes events through to another sink while recording the game.
+pub(crate) struct Recorder<'a> {
+    inner: &'a mut dyn Sink,
+    started: Instant,
+    pub(crate) transcript: Transcript,
+}
+
+impl<'a> Recorder<'a> {
+    pub(crate) fn new(inner: &'a mut dyn Sink, transcript: Transcript) -> Self {
+        Self {
+            inner,
+            started: Instant::now(),
+            transcript,
+        }
+    }
+}
+
+impl Sink for Recorder<'_> {
+    fn event(&mut self, event: &Event) -> io::Result<()> {
+        // Only the first game is recorded, not those started with /new.
+        if self.transcript.won.is_some() {
+            return self.inner.event(event);
+        }
+        match event {
+            Event::Guess { word, guess } => self.transcript.steps.push(Step {
+                word: String::from(*word),
+                pattern: guess.pattern(),
+                at_ms: self.started.elapsed().as_millis() as u64,
+            }),
+            Event::Undo { .. } => {
+                self.transcript.steps.pop();
+            }
+            Event::Outcome { won, .. } => self.transcript.won = Some(*won),
+            _ => {}
+        }
+        self.inner.event(event)
+    }
+
+    fn prompt(&mut self) -> io::Result<()> {
+        self.inner.prompt()
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Steps through a transcript, waiting for Enter between guesses. Each guess
+/// is scored again against the recorded word and any difference with the
+/// recorded feedback is pointed out.
+pub(crate) fn replay<R: BufRead, W: Write>(
+    transcript: &Transcript,
+    dict: &WordList,
+    mut input: R,
+    mut output: W,
+) -> Result<(), Error> {
+    writeln!(output, "{}", transcript.title())?;
+    if transcript.dict_hash != dict_hash(dict) {
+        writeln!(
+            output,
+            "note: recorded with a different word list ({}).",
+            transcript.dict_hash
+        )?;
+    }
+
+    let mut game = Game::in_language(transcript.language, transcript.word.clone());
+    let mut line = String::new();
+    for (i, step) in transcript.steps.iter().enumerate() {
+        write!(output, "[Enter] ")?;
+        output.flush()?;
+        line.clear();
+        input.read_line(&mut line)?;
+
+        let recorded = render_pattern(&step.pattern);
+        write!(
+            output,
+            "{}. {} {} {}",
+            i + 1,
+            step.word.to_uppercase(),
+            recorded,
+            format_ms(step.at_ms)
+        )?;
+        match game.guess(step.word.clone()) {
+            Ok(guess) if guess.pattern() == step.pattern => writeln!(output)?,
+            Ok(guess) => writeln!(output, "  (now scored {})", guess)?,
+            Err(e) => writeln!(output, "  (now rejected: {})", e)?,
+        }
+    }
+
+    writeln!(
+        output,
+        "The word was {}, {}.",
+        transcript.word,
+        transcript.outcome()
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        event::{Format, Writer},
+        lang::SPANISH,
+    };
+
+    fn recorded(word: &str, input: &str) -> Transcript {
+        let dict = WordList::from(word);
+        let mut game = Game::with_word(&dict, word).unwrap();
+        let transcript = Transcript::new(&game, &dict);
+        let mut output = vec![];
+        let mut writer = Writer::new(&mut output, Format::Text);
+        let mut recorder = Recorder::new(&mut writer, transcript);
+        game.repl(&dict, input.as_bytes(), &mut recorder).unwrap();
+        recorder.transcript
+    }
+
+    #[test]
+    fn test_record() {
+        let transcript = recorded("fudge", "reads\nlol\nfudge\n");
+        assert_eq!(transcript.mode, Mode::Word);
+        assert_eq!(transcript.word, "fudge");
+        assert_eq!(transcript.dict_hash, dict_hash(&WordList::from("fudge")));
+        assert_eq!(transcript.won, Some(true));
+        let guesses: Vec<(&str, &str)> = transcript
+            .steps
+            .iter()
+            .map(|s| (s.word.as_str(), s.pattern.as_str()))
+            .collect();
+        assert_eq!(guesses, vec![("reads", "01010"), ("fudge", "22222")]);
+
+        assert_eq!(recorded("fudge", "/exit\n").won, Some(false));
+
+        // Guesses taken back aren't replayed.
+        let transcript = recorded("fudge", "reads\n/undo\nfudge\n");
+        assert_eq!(transcript.steps.len(), 1);
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let transcript = recorded("fudge", "reads\nfudge\n");
+        assert_eq!(
+            Transcript::from_json(&transcript.to_json()).unwrap(),
+            transcript
+        );
+
+        assert!(Transcript::from_json("{}").is_err());
+        assert!(Transcript::from_json("nope").is_err());
+
+        let json = transcript.to_json();
+        assert!(json.contains(r#""language":"en""#));
+        let older = json.replace(r#""language":"en","#, "");
+        assert_eq!(Transcript::from_json(&older).unwrap().language, &ENGLISH);
+        let unknown = json.replace(r#""language":"en""#, r#""language":"xx""#);
+        assert!(Transcript::from_json(&unknown).is_err());
+    }
+
+    #[test]
+    fn test_replay_language() {
+        let dict = SPANISH.words();
+        let mut game = Game::with_word(dict, "señal").unwrap();
+        let transcript = Transcript::new(&game, dict);
+        let mut output = vec![];
+        let mut writer = Writer::new(&mut output, Format::Text);
+        let mut recorder = Recorder::new(&mut writer, transcript);
+        game.repl(dict, "SEÑAL\n".as_bytes(), &mut recorder)
+            .unwrap();
+        let transcript = Transcript::from_json(&recorder.transcript.to_json()).unwrap();
+        assert_eq!(transcript.language, &SPANISH);
+
+        let mut output = vec![];
+        replay(&transcript, dict, "\n".as_bytes(), &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("1. SEÑAL 🟩🟩🟩🟩🟩 "), "{}", output);
+        assert!(!output.contains("now"));
+        assert!(transcript.to_markdown().contains("`SEÑAL`"));
+    }
+
+    #[test]
+    fn test_markdown() {
+        let transcript = Transcript {
+            mode: Mode::Daily,
+            seed: Some(123),
+            puzzle: Some(123),
+            language: &ENGLISH,
+            dict_hash: dict_hash(&WordList::from("fudge")),
+            word: String::from("fudge"),
+            started: 0,
+            steps: vec![
+                Step {
+                    word: String::from("reads"),
+                    pattern: String::from("01010"),
+                    at_ms: 4_200,
+                },
+                Step {
+                    word: String::from("fudge"),
+                    pattern: String::from("22222"),
+                    at_ms: 75_000,
+                },
+            ],
+            won: Some(true),
+        };
+        let md = transcript.to_markdown();
+        assert!(md.starts_with("# Wordler #123\n"));
+        assert!(md.contains("- Outcome: won in 2 guesses (1:15)\n"));
+        assert!(md.contains("| 1 | `READS` | ⬛🟨⬛🟨⬛ | 0:04 |\n"));
+        assert!(md.contains("| 2 | `FUDGE` | 🟩🟩🟩🟩🟩 | 1:15 |\n"));
+    }
+
+    #[test]
+    fn test_replay() {
+        let mut transcript = recorded("fudge", "reads\nfudge\n");
+        transcript.steps[0].pattern = String::from("00000");
+
+        let mut output = vec![];
+        replay(
+            &transcript,
+            &WordList::from("other"),
+            "\n\n".as_bytes(),
+            &mut output,
+        )
+        .unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("note: recorded with a different word list"));
+        assert!(output.contains("1. READS ⬛⬛⬛⬛⬛ 0:00  (now scored ⬛🟨⬛🟨⬛)\n"));
+        assert!(output.contains("2. FUDGE 🟩🟩🟩🟩🟩 0:00\n"));
+        assert!(output.ends_with("The word was fudge, won in 2 guesses (0:00).\n"));
+    }
+}
@@ -0,0 +1,493 @@
+//! Full-screen terminal interface with the classic grid and an on-screen
+//! keyboard. Drawn with plain ANSI escape codes, the terminal is switched to
+//! raw mode with `stty` for the duration of the game.
+//!
+//! Keys: letters to type, Backspace to erase, Enter to submit and Ctrl-C to
+//! quit.
+
+use std::{
+    io::{self, BufRead, BufReader, Read, Write},
+    process::{Command, Stdio},
+    thread,
+    time::Duration,
+};
+
+use crate::{palette::Palette, word::WordList, Error, ErrorKind, Game, GuessChar};
+
+/// Number of rows in the grid.
+const MAX_GUESSES: usize = 6;
+
+/// Delay between two tiles flipping over.
+const FLIP_DELAY: Duration = Duration::from_millis(250);
+
+const KEYBOARD: [&str; 3] = ["qwertyuiop", "asdfghjkl", "zxcvbnm"];
+
+#[derive(PartialEq, Eq, Debug)]
+enum Key {
+    Letter(char),
+    Backspace,
+    Enter,
+    Quit,
+}
+
+/// What the interface should do after a key press.
+#[derive(PartialEq, Eq, Debug)]
+enum Action {
+    /// Redraw the screen.
+    Redraw,
+    /// Animate the tiles of the guess that was just submitted.
+    Reveal,
+    /// Leave the interface.
+    Quit,
+}
+
+struct Tui<'a> {
+    dict: &'a WordList,
+    game: Game,
+
+    /// Letters typed for the current row.
+    input: String,
+
+    /// Short-lived message shown under the grid, cleared on the next key.
+    toast: Option<String>,
+
+    palette: Palette,
+}
+
+impl<'a> Tui<'a> {
+    fn new(dict: &'a WordList, game: Game) -> Self {
+        Self {
+            dict,
+            game,
+            input: String::new(),
+            toast: None,
+            palette: Palette::default(),
+        }
+    }
+
+    /// Rows of the grid, one per allowed guess.
+    fn rows(&self) -> usize {
+        self.game.max_guesses.unwrap_or(MAX_GUESSES)
+    }
+
+    fn length(&self) -> usize {
+        self.game.word.chars().count()
+    }
+
+    fn over(&self) -> bool {
+        !self.game.keep_going || self.game.history.len() >= self.rows()
+    }
+
+    fn handle_key(&mut self, key: Key) -> Action {
+        if self.over() {
+            return match key {
+                Key::Enter | Key::Quit => Action::Quit,
+                _ => Action::Redraw,
+            };
+        }
+
+        self.toast = None;
+        match key {
+            Key::Letter(c) if self.input.chars().count() < self.length() => {
+                let letter = self.game.language.fold(&c.to_string());
+                if letter.chars().all(|l| self.game.language.is_letter(l)) {
+                    self.input.push_str(&letter);
+                }
+            }
+            Key::Letter(_) => {}
+            Key::Backspace => {
+                self.input.pop();
+            }
+            Key::Enter => return self.submit(),
+            Key::Quit => return Action::Quit,
+        }
+        Action::Redraw
+    }
+
+    fn submit(&mut self) -> Action {
+        if self.input.chars().count() == self.length() && !self.dict.contains(&self.input) {
+            let e = Error::from(ErrorKind::NotInWordList(self.input.clone()));
+            self.toast = Some(e.to_string());
+            return Action::Redraw;
+        }
+
+        match self.game.guess(self.input.clone()) {
+            Ok(guess) => {
+                self.input.clear();
+                if guess.correct() {
+                    self.game.keep_going = false;
+                    self.toast = Some(String::from("Congrats! 🎉"));
+                } else if self.over() {
+                    self.toast = Some(format!("The word was {}.", self.game.word));
+                }
+                Action::Reveal
+            }
+            Err(e) => {
+                self.toast = Some(e.to_string());
+                Action::Redraw
+            }
+        }
+    }
+
+    /// Draws the whole screen. Only the first `revealed` tiles of the last
+    /// guess are colored, which is used to animate them flipping over.
+    fn render(&self, revealed: usize) -> String {
+        let mut screen = String::from("\x1b[H\x1b[2J\r\n        W O R D L E R\r\n\r\n");
+
+        let last = self.game.history.len().saturating_sub(1);
+        for row in 0..self.rows() {
+            screen.push_str("      ");
+            match self.game.history.get(row) {
+                Some((word, guess)) => {
+                    let shown = if row == last { revealed } else { self.length() };
+                    for (i, (letter, result)) in word.chars().zip(&guess.inner).enumerate() {
+                        if i < shown {
+                            screen.push_str(&tile(self.palette, letter, Some(*result)));
+                        } else {
+                            screen.push_str(&tile(self.palette, letter, None));
+                        }
+                    }
+                }
+                None if row == self.game.history.len() && !self.over() => {
+                    let mut typed = self.input.chars();
+                    for _ in 0..self.length() {
+                        screen.push_str(&tile(self.palette, typed.next().unwrap_or(' '), None));
+                    }
+                }
+                None => {
+                    for _ in 0..self.length() {
+                        screen.push_str(&tile(self.palette, ' ', None));
+                    }
+                }
+            }
+            screen.push_str("\r\n");
+        }
+
+        screen.push_str("\r\n");
+        screen.push_str(self.toast.as_deref().unwrap_or_default());
+        screen.push_str("\r\n\r\n");
+
+        // Don't give away the colors of tiles that haven't flipped yet.
+        let keyboard = if revealed < self.length() {
+            let mut game_before = self.game.history.clone();
+            game_before.pop();
+            Game::keyboard_of(&game_before)
+        } else {
+            self.game.keyboard()
+        };
+        // Letters of the language that aren't on a QWERTY keyboard get a row
+        // of their own.
+        let extra: String = self
+            .game
+            .language
+            .alphabet
+            .iter()
+            .filter(|l| !KEYBOARD.iter().any(|row| row.contains(**l)))
+            .collect();
+        let rows = KEYBOARD.iter().copied().chain(Some(extra.as_str()));
+        for (indent, row) in rows.filter(|row| !row.is_empty()).enumerate() {
+            screen.push_str(&" ".repeat(indent * 2));
+            for letter in row.chars() {
+                screen.push_str(&key(self.palette, letter, keyboard.get(&letter).copied()));
+            }
+            screen.push_str("\r\n");
+        }
+
+        if self.over() {
+            screen.push_str("\r\nPress Enter to quit.\r\n");
+        }
+        screen
+    }
+}
+
+fn tile(palette: Palette, letter: char, result: Option<GuessChar>) -> String {
+    // Keep letters like ß, whose capital is more than one letter, as they are.
+    let mut upper = letter.to_uppercase();
+    let letter = match (upper.next(), upper.next()) {
+        (Some(upper), None) => upper,
+        _ => letter,
+    };
+    match result {
+        Some(result) => format!("{} {} \x1b[0m ", palette.ansi(result), letter),
+        None => format!("[{}] ", letter),
+    }
+}
+
+fn key(palette: Palette, letter: char, result: Option<GuessChar>) -> String {
+    match result {
+        Some(result) => format!("{}{}\x1b[0m ", palette.ansi(result), letter),
+        None => format!("{} ", letter),
+    }
+}
+
+/// Reads the next key press from a terminal in raw mode.
+///
+/// The terminal writes a whole escape sequence at once, so an escape byte
+/// with nothing buffered after it is a lone Esc press and is ignored rather
+/// than waiting for the rest of a sequence that never comes.
+fn read_key<R: Read>(input: &mut BufReader<R>) -> io::Result<Key> {
+    let mut byte = [0; 1];
+    loop {
+        if input.read(&mut byte)? == 0 {
+            return Ok(Key::Quit);
+        }
+        match byte[0] {
+            b'\r' | b'\n' => return Ok(Key::Enter),
+            0x7f | 0x08 => return Ok(Key::Backspace),
+            0x03 | 0x04 => return Ok(Key::Quit),
+            b if b.is_ascii_alphabetic() => return Ok(Key::Letter(b as char)),
+            // Letters such as ñ or ü arrive as multi-byte UTF-8 sequences.
+            b @ 0xc0..=0xf7 => {
+                let len = match b {
+                    0xc0..=0xdf => 2,
+                    0xe0..=0xef => 3,
+                    _ => 4,
+                };
+                let mut bytes = [b, 0, 0, 0];
+                input.read_exact(&mut bytes[1..len])?;
+                let letter = std::str::from_utf8(&bytes[..len])
+                    .ok()
+                    .and_then(|s| s.chars().next())
+                    .filter(|c| c.is_alphabetic());
+                if let Some(letter) = letter {
+                    return Ok(Key::Letter(letter));
+                }
+            }
+            // Skip escape sequences such as arrow keys.
+            0x1b => skip_escape(input)?,
+            _ => {}
+        }
+    }
+}
+
+/// Skips the rest of an escape sequence whose escape byte was just read.
+fn skip_escape<R: Read>(input: &mut BufReader<R>) -> io::Result<()> {
+    // Esc on its own, or followed by another key that is left to be read.
+    let introducer = match input.buffer().first() {
+        Some(&b @ (b'[' | b'O')) => b,
+        _ => return Ok(()),
+    };
+    input.consume(1);
+    let mut byte = [0; 1];
+    while !input.buffer().is_empty() {
+        input.read_exact(&mut byte)?;
+        // Control sequences end with a byte in the range @ to ~.
+        if introducer == b'O' || (0x40..=0x7e).contains(&byte[0]) {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Puts the terminal in raw mode and restores it when dropped.
+struct RawMode {
+    saved: String,
+}
+
+impl RawMode {
+    fn enable() -> io::Result<Self> {
+        let saved = stty(&["-g"])?;
+        stty(&["raw", "-echo"])?;
+        print!("\x1b[?1049h\x1b[?25l");
+        io::stdout().flush()?;
+        Ok(Self {
+            saved: saved.trim().to_string(),
+        })
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        print!("\x1b[?25h\x1b[?1049l");
+        let _ = io::stdout().flush();
+        let _ = stty(&[self.saved.as_str()]);
+    }
+}
+
+pub(crate) fn stty(args: &[&str]) -> io::Result<String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .output()?;
+    if !output.status.success() {
+        return Err(io::Error::other("stty failed, is stdin a terminal?"));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Plays the game full screen until the player quits, and returns it.
+pub(crate) fn run(dict: &WordList, game: Game, palette: Palette) -> Result<Game, Error> {
+    let mut tui = Tui::new(dict, game);
+    tui.palette = palette;
+    let _raw = RawMode::enable()?;
+    let mut stdin = BufReader::new(io::stdin().lock());
+    let mut stdout = io::stdout();
+    let length = tui.length();
+
+    write!(stdout, "{}", tui.render(length))?;
+    stdout.flush()?;
+    loop {
+        match tui.handle_key(read_key(&mut stdin)?) {
+            Action::Quit => return Ok(tui.game),
+            Action::Redraw => write!(stdout, "{}", tui.render(length))?,
+            Action::Reveal => {
+                for revealed in 1..=length {
+                    write!(stdout, "{}", tui.render(revealed))?;
+                    stdout.flush()?;
+                    thread::sleep(FLIP_DELAY);
+                }
+            }
+        }
+        stdout.flush()?;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::lang::GERMAN;
+
+    /// Removes escape codes so the screen can be compared as plain text.
+    fn plain(screen: &str) -> String {
+        let mut out = String::new();
+        let mut chars = screen.chars();
+        while let Some(c) = chars.next() {
+            if c == '\x1b' {
+                for c in chars.by_ref() {
+                    if c.is_ascii_alphabetic() {
+                        break;
+                    }
+                }
+            } else if c != '\r' {
+                out.push(c);
+            }
+        }
+        out
+    }
+
+    fn typed(tui: &mut Tui, word: &str) -> Action {
+        for c in word.chars() {
+            tui.handle_key(Key::Letter(c));
+        }
+        tui.handle_key(Key::Enter)
+    }
+
+    #[test]
+    fn test_typing() {
+        let dict = WordList::from("fudge\nreads");
+        let mut tui = Tui::new(&dict, Game::new(String::from("fudge")));
+        for c in "readsx".chars() {
+            tui.handle_key(Key::Letter(c));
+        }
+        assert_eq!(tui.input, "reads");
+        tui.handle_key(Key::Backspace);
+        tui.handle_key(Key::Backspace);
+        assert_eq!(tui.input, "rea");
+
+        assert_eq!(tui.handle_key(Key::Enter), Action::Redraw);
+        assert_eq!(
+            tui.toast.as_deref(),
+            Some("guess too short, guesses must be 5 letters.")
+        );
+        assert_eq!(tui.handle_key(Key::Letter('l')), Action::Redraw);
+        assert_eq!(tui.toast, None);
+        assert!(plain(&tui.render(5)).contains("[R] [E] [A] [L] [ ]"));
+    }
+
+    #[test]
+    fn test_not_in_word_list() {
+        let dict = WordList::from("fudge\nreads");
+        let mut tui = Tui::new(&dict, Game::new(String::from("fudge")));
+        assert_eq!(typed(&mut tui, "lodge"), Action::Redraw);
+        assert_eq!(tui.toast.as_deref(), Some("lodge is not in the word list."));
+        assert!(tui.game.history.is_empty());
+    }
+
+    #[test]
+    fn test_win() {
+        let dict = WordList::from("fudge\nreads");
+        let mut tui = Tui::new(&dict, Game::new(String::from("fudge")));
+        assert_eq!(typed(&mut tui, "reads"), Action::Reveal);
+        assert_eq!(typed(&mut tui, "fudge"), Action::Reveal);
+        assert!(tui.over());
+
+        let screen = plain(&tui.render(5));
+        assert!(screen.contains(" R   E   A   D   S "));
+        assert!(screen.contains(" F   U   D   G   E "));
+        assert!(screen.contains("Congrats! 🎉"));
+        assert!(screen.contains("Press Enter to quit."));
+        assert_eq!(tui.handle_key(Key::Letter('a')), Action::Redraw);
+        assert_eq!(tui.handle_key(Key::Enter), Action::Quit);
+    }
+
+    #[test]
+    fn test_loss() {
+        let dict = WordList::from("fudge\nreads");
+        let mut tui = Tui::new(&dict, Game::new(String::from("fudge")));
+        for _ in 0..MAX_GUESSES {
+            typed(&mut tui, "reads");
+        }
+        assert!(tui.over());
+        assert_eq!(tui.toast.as_deref(), Some("The word was fudge."));
+    }
+
+    #[test]
+    fn test_reveal_hides_keyboard() {
+        let dict = WordList::from("fudge\nreads");
+        let mut tui = Tui::new(&dict, Game::new(String::from("fudge")));
+        typed(&mut tui, "reads");
+
+        let hidden = tui.render(0);
+        assert!(!hidden.contains(&format!(
+            "{}e\x1b[0m",
+            Palette::Classic.ansi(GuessChar::OutOfPlace)
+        )));
+        let shown = tui.render(5);
+        assert!(shown.contains(&format!(
+            "{}e\x1b[0m",
+            Palette::Classic.ansi(GuessChar::OutOfPlace)
+        )));
+        assert!(shown.contains(&format!(
+            "{}r\x1b[0m",
+            Palette::Classic.ansi(GuessChar::Absent)
+        )));
+    }
+
+    #[test]
+    fn test_language() {
+        let dict = WordList::parse_in(&GERMAN, "größe\nhände");
+        let mut tui = Tui::new(&dict, Game::with_word(&dict, "größe").unwrap());
+        for c in "HÉÄnde".chars() {
+            tui.handle_key(Key::Letter(c));
+        }
+        assert_eq!(tui.input, "hände");
+        assert_eq!(tui.handle_key(Key::Enter), Action::Reveal);
+
+        let screen = plain(&tui.render(5));
+        assert!(screen.contains(" H   Ä   N   D   E "));
+        assert!(screen.contains("ä ö ü ß"));
+        assert_eq!(typed(&mut tui, "größe"), Action::Reveal);
+        assert!(plain(&tui.render(5)).contains(" G   R   Ö   ß   E "));
+    }
+
+    #[test]
+    fn test_read_key() {
+        let mut input = BufReader::new("añ\x1b[A\x1b[1;5C\x7f\r\x03".as_bytes());
+        assert_eq!(read_key(&mut input).unwrap(), Key::Letter('a'));
+        assert_eq!(read_key(&mut input).unwrap(), Key::Letter('ñ'));
+        assert_eq!(read_key(&mut input).unwrap(), Key::Backspace);
+        assert_eq!(read_key(&mut input).unwrap(), Key::Enter);
+        assert_eq!(read_key(&mut input).unwrap(), Key::Quit);
+        assert_eq!(read_key(&mut input).unwrap(), Key::Quit);
+    }
+
+    #[test]
+    fn test_read_lone_escape() {
+        // Each part arrives in its own read, like separate key presses.
+        let presses = "\x1b".as_bytes().chain("a".as_bytes());
+        let mut input = BufReader::new(presses.chain("\x1bOAb".as_bytes()));
+        assert_eq!(read_key(&mut input).unwrap(), Key::Letter('a'));
+        assert_eq!(read_key(&mut input).unwrap(), Key::Letter('b'));
+        assert_eq!(read_key(&mut input).unwrap(), Key::Quit);
+    }
+}
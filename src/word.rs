@@ -0,0 +1,306 @@
+//! Compact representation of words and word lists.
+//!
+//! A `Word` packs up to `MAX_LENGTH` letters into a single `u64`, five bits
+//! per letter with the first letter in the highest bits, so comparing packed
+//! words orders them alphabetically. A `WordList` keeps them sorted, giving
+//! constant time access by index and binary search membership.
+
+use std::fmt::{self, Debug, Display};
+
+use rand::Rng;
+
+use crate::{
+    lang::{Language, ENGLISH},
+    Guess, GuessChar,
+};
+
+/// Longest word that fits in a `Word`.
+pub(crate) const MAX_LENGTH: usize = 12;
+
+const BITS: usize = 5;
+const MASK: u64 = (1 << BITS) - 1;
+
+/// Every letter a `Word` can hold, across all languages, in code point order
+/// so packed words sort like strings.
+const LETTERS: [char; 31] = [
+    'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's',
+    't', 'u', 'v', 'w', 'x', 'y', 'z', 'ß', 'ä', 'ñ', 'ö', 'ü',
+];
+
+/// A word of lowercase letters. Letters are stored as their position in
+/// `LETTERS` plus one, an empty slot is 0.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub(crate) struct Word(u64);
+
+impl Word {
+    /// Packs a word, or returns `None` if it is empty, too long or contains
+    /// anything but lowercase letters from `LETTERS`.
+    pub(crate) fn parse(word: &str) -> Option<Self> {
+        let mut packed = 0;
+        let mut len = 0;
+        for (i, c) in word.chars().enumerate() {
+            if i >= MAX_LENGTH {
+                return None;
+            }
+            let index = LETTERS.iter().position(|&l| l == c)?;
+            packed |= (index as u64 + 1) << Self::shift(i);
+            len += 1;
+        }
+        if len == 0 {
+            return None;
+        }
+        Some(Self(packed))
+    }
+
+    fn shift(i: usize) -> usize {
+        (MAX_LENGTH - 1 - i) * BITS
+    }
+
+    /// The letter at position `i`, if the word is long enough.
+    pub(crate) fn get(&self, i: usize) -> Option<char> {
+        if i >= MAX_LENGTH {
+            return None;
+        }
+        match (self.0 >> Self::shift(i)) & MASK {
+            0 => None,
+            n => Some(LETTERS[n as usize - 1]),
+        }
+    }
+
+    pub(crate) fn letters(&self) -> impl Iterator<Item = char> + '_ {
+        (0..MAX_LENGTH).map_while(|i| self.get(i))
+    }
+
+    /// The set of letters in the word, with bit `n` standing for the `n`th
+    /// entry of `LETTERS`.
+    pub(crate) fn mask(&self) -> u32 {
+        (0..MAX_LENGTH)
+            .map(|i| (self.0 >> Self::shift(i)) & MASK)
+            .take_while(|&n| n != 0)
+            .fold(0, |mask, n| mask | 1 << (n - 1))
+    }
+
+    pub(crate) fn contains(&self, letter: char) -> bool {
+        LETTERS
+            .iter()
+            .position(|&l| l == letter)
+            .is_some_and(|n| self.mask() & 1 << n != 0)
+    }
+
+    /// The feedback for guessing this word against `answer`, scored the same
+    /// way as `Game::guess`.
+    pub(crate) fn score(&self, answer: &Word) -> Guess {
+        Guess {
+            inner: self.feedback(answer).collect(),
+        }
+    }
+
+    /// The feedback of `score` as a number, each letter a base 3 digit, for
+    /// grouping answers without allocating.
+    pub(crate) fn score_code(&self, answer: &Word) -> u32 {
+        self.feedback(answer)
+            .fold(0, |code, gc| code * 3 + gc as u32)
+    }
+
+    fn feedback<'a>(&'a self, answer: &'a Word) -> impl Iterator<Item = GuessChar> + 'a {
+        let letters = answer.mask();
+        (0..MAX_LENGTH)
+            .map(|i| (self.0 >> Self::shift(i)) & MASK)
+            .take_while(|&n| n != 0)
+            .enumerate()
+            .map(move |(i, n)| {
+                if (answer.0 >> Self::shift(i)) & MASK == n {
+                    GuessChar::Correct
+                } else if letters & 1 << (n - 1) != 0 {
+                    GuessChar::OutOfPlace
+                } else {
+                    GuessChar::Absent
+                }
+            })
+    }
+}
+
+impl Display for Word {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for l in self.letters() {
+            write!(f, "{}", l)?;
+        }
+        Ok(())
+    }
+}
+
+impl Debug for Word {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Word({:?})", self.to_string())
+    }
+}
+
+/// A sorted list of distinct words in one language.
+#[derive(Clone, Debug)]
+pub(crate) struct WordList {
+    words: Vec<Word>,
+    language: &'static Language,
+}
+
+impl WordList {
+    /// Reads one English word per line, skipping lines that aren't valid
+    /// words.
+    pub(crate) fn parse(text: &str) -> Self {
+        Self::parse_in(&ENGLISH, text)
+    }
+
+    /// Reads one word per line, folding accents the way `language` does and
+    /// skipping lines with letters outside of its alphabet.
+    pub(crate) fn parse_in(language: &'static Language, text: &str) -> Self {
+        let mut words: Vec<Word> = text
+            .lines()
+            .map(|l| language.fold(l.trim()))
+            .filter(|w| w.chars().all(|c| language.is_letter(c)))
+            .filter_map(|w| Word::parse(&w))
+            .collect();
+        words.sort_unstable();
+        words.dedup();
+        Self { words, language }
+    }
+
+    pub(crate) fn language(&self) -> &'static Language {
+        self.language
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    pub(crate) fn get(&self, i: usize) -> Option<Word> {
+        self.words.get(i).copied()
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = Word> + '_ {
+        self.words.iter().copied()
+    }
+
+    /// The words matching `predicate`, in the same language.
+    pub(crate) fn filter<P: FnMut(&Word) -> bool>(&self, mut predicate: P) -> Self {
+        Self {
+            words: self
+                .words
+                .iter()
+                .copied()
+                .filter(|w| predicate(w))
+                .collect(),
+            language: self.language,
+        }
+    }
+
+    /// Whether `word` is in the list.
+    pub(crate) fn contains(&self, word: &str) -> bool {
+        Word::parse(word).is_some_and(|w| self.words.binary_search(&w).is_ok())
+    }
+
+    /// Picks a word uniformly at random.
+    pub(crate) fn choose<R: Rng>(&self, rng: &mut R) -> Option<Word> {
+        if self.is_empty() {
+            return None;
+        }
+        self.get(rng.gen_range(0..self.len()))
+    }
+}
+
+impl From<&str> for WordList {
+    fn from(text: &str) -> Self {
+        Self::parse(text)
+    }
+}
+
+/// A list holding only "fudge", shared by tests that need a `'static` list.
+#[cfg(test)]
+pub(crate) fn fudge() -> &'static WordList {
+    static FUDGE: std::sync::LazyLock<WordList> =
+        std::sync::LazyLock::new(|| WordList::from("fudge"));
+    &FUDGE
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_word() {
+        let word = Word::parse("fudge").unwrap();
+        assert_eq!(word.to_string(), "fudge");
+        assert_eq!(word.get(0), Some('f'));
+        assert_eq!(word.get(4), Some('e'));
+        assert_eq!(word.get(5), None);
+        assert!(word.contains('g'));
+        assert!(!word.contains('a'));
+        assert!(!word.contains('1'));
+        assert_eq!(word.mask(), 1 << 3 | 1 << 4 | 1 << 5 | 1 << 6 | 1 << 20);
+
+        assert_eq!(
+            Word::parse("zzzzzzzzzzzz").unwrap().letters().count(),
+            MAX_LENGTH
+        );
+        assert_eq!(Word::parse("zzzzzzzzzzzzz"), None);
+        assert_eq!(Word::parse(""), None);
+        assert_eq!(Word::parse("Fudge"), None);
+        assert_eq!(Word::parse("héllo"), None);
+
+        let word = Word::parse("größe").unwrap();
+        assert_eq!(word.to_string(), "größe");
+        assert_eq!(word.letters().count(), 5);
+        assert!(word.contains('ß'));
+        assert!(!word.contains('ñ'));
+    }
+
+    #[test]
+    fn test_score() {
+        let answer = Word::parse("sassy").unwrap();
+        assert_eq!(
+            Word::parse("space").unwrap().score(&answer).pattern(),
+            "20100"
+        );
+        let mut game = crate::Game::new(String::from("sassy"));
+        assert_eq!(
+            game.guess(String::from("space")).unwrap(),
+            Word::parse("space").unwrap().score(&answer)
+        );
+    }
+
+    #[test]
+    fn test_word_order() {
+        let words = [
+            "a", "ab", "abc", "b", "fudge", "fudges", "reads", "z", "zß", "ñ", "ü",
+        ];
+        for pair in words.windows(2) {
+            assert!(Word::parse(pair[0]) < Word::parse(pair[1]), "{:?}", pair);
+        }
+    }
+
+    #[test]
+    fn test_word_list() {
+        let list = WordList::parse("reads\nfudge\n\nFUDGE\nfudge\nab1de\nbread\n");
+        assert_eq!(list.len(), 3);
+        assert_eq!(
+            list.iter().map(|w| w.to_string()).collect::<Vec<_>>(),
+            ["bread", "fudge", "reads"]
+        );
+        assert_eq!(list.get(1).unwrap().to_string(), "fudge");
+        assert_eq!(list.get(3), None);
+        assert!(list.contains("reads"));
+        assert!(!list.contains("fudges"));
+        assert!(!list.contains("FUDGE"));
+        assert_eq!(list.language(), &ENGLISH);
+        let r = list.filter(|w| w.contains('r'));
+        assert_eq!(r.len(), 2);
+        assert!(!r.contains("fudge"));
+        assert!(WordList::parse("größe").is_empty());
+
+        let mut rng = rand::thread_rng();
+        assert!(list.contains(&list.choose(&mut rng).unwrap().to_string()));
+        assert_eq!(WordList::parse("").choose(&mut rng), None);
+    }
+}